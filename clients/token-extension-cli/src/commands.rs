@@ -0,0 +1,510 @@
+use {
+    crate::{mint::MintPlan, spec::MintSpec},
+    anyhow::{anyhow, bail, Context, Result},
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{
+        account::Account,
+        instruction::{AccountMeta, Instruction},
+        program_option::COption,
+        pubkey::Pubkey,
+        signature::{read_keypair_file, Keypair, Signature, Signer},
+        system_instruction,
+    },
+    spl_associated_token_account::instruction::create_associated_token_account_idempotent,
+    spl_token_2022::extension::{
+        interest_bearing_mint::InterestBearingConfig,
+        transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+        transfer_hook::TransferHook,
+        BaseStateWithExtensions,
+    },
+    spl_token_metadata_interface::state::Field as MetaField,
+    std::{fmt::Display, path::Path},
+    token_extension_client::{
+        accounts, instruction,
+        pda::{find_program_config_address, get_associated_token_address},
+        state::{
+            decode_account, token_metadata, unpack_mint, unpack_token_account, AccessRequest,
+            DelegateGovernance, DelegateProposal, FreezeRecord, KycConfig, MintWithExtensions,
+            ProgramConfig, RateGovernance, TokenAccountWithExtensions,
+        },
+        transaction::TransactionBuilder,
+        Field, TransferMemo,
+    },
+};
+
+pub struct Config {
+    pub rpc: RpcClient,
+    pub keypair: Keypair,
+    pub compute_unit_price: Option<u64>,
+}
+
+impl Config {
+    fn payer(&self) -> Pubkey {
+        self.keypair.pubkey()
+    }
+
+    fn send(
+        &self,
+        instructions: Vec<Instruction>,
+        extra_signers: &[&Keypair],
+    ) -> Result<Signature> {
+        let mut builder = TransactionBuilder::new(self.payer()).instructions(instructions);
+        if let Some(price) = self.compute_unit_price {
+            builder = builder.compute_unit_price(price);
+        }
+
+        let mut signers = vec![&self.keypair];
+        signers.extend_from_slice(extra_signers);
+
+        let transaction = builder.build(&signers, self.rpc.get_latest_blockhash()?)?;
+        Ok(self.rpc.send_and_confirm_transaction(&transaction)?)
+    }
+
+    fn fetch_account(&self, address: &Pubkey) -> Result<Option<Account>> {
+        Ok(self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())?
+            .value)
+    }
+
+    fn fetch_mint(&self, mint: &Pubkey) -> Result<MintWithExtensions> {
+        let account = self
+            .fetch_account(mint)?
+            .ok_or_else(|| anyhow!("mint {mint} not found"))?;
+        if account.owner != spl_token_2022::ID {
+            bail!("{mint} is not a Token-2022 mint");
+        }
+
+        Ok(unpack_mint(&account.data)?)
+    }
+
+    // Creates the associated token account of `owner` if it does not exist yet.
+    fn create_associated_token_account(&self, owner: &Pubkey, mint: &Pubkey) -> Instruction {
+        create_associated_token_account_idempotent(&self.payer(), owner, mint, &spl_token_2022::ID)
+    }
+}
+
+pub fn create_mint(config: &Config, spec: &Path, mint_keypair: Option<&Path>) -> Result<()> {
+    let spec = MintSpec::load(spec)?;
+    let mint = match mint_keypair {
+        Some(path) => read_keypair(path)?,
+        None => Keypair::new(),
+    };
+
+    let plan = MintPlan::new(&spec, mint.pubkey(), &config.payer())?;
+    let lamports = config
+        .rpc
+        .get_minimum_balance_for_rent_exemption(plan.rent_len()?)?;
+
+    let signature = config.send(plan.instructions(&config.payer(), lamports)?, &[&mint])?;
+
+    println!("Mint {}", mint.pubkey());
+    println!("Extensions {:?}", plan.extension_types());
+    println!("Signature {signature}");
+
+    Ok(())
+}
+
+// The program only mints to the associated token account of the mint authority.
+pub fn mint_to(config: &Config, mint: Pubkey, amount: u64) -> Result<()> {
+    let payer = config.payer();
+    let token_account = get_associated_token_address(&payer, &mint);
+
+    let signature = config.send(
+        vec![
+            config.create_associated_token_account(&payer, &mint),
+            instruction::mint_to(
+                accounts::MintTo {
+                    mint,
+                    token_2022_program: spl_token_2022::ID,
+                    associated_token: token_account,
+                    authority: payer,
+                    program_config: find_program_config_address().0,
+                },
+                amount,
+            ),
+        ],
+        &[],
+    )?;
+
+    println!("Minted {amount} to {token_account}");
+    println!("Signature {signature}");
+
+    Ok(())
+}
+
+// Transfer mints charge the fee computed for the current epoch, memos go through memo_transfer.
+pub fn transfer(
+    config: &Config,
+    mint: Pubkey,
+    amount: u64,
+    recipient: Pubkey,
+    memo: Option<String>,
+) -> Result<()> {
+    let payer = config.payer();
+    let mint_state = config.fetch_mint(&mint)?;
+    let decimals = mint_state.base.decimals;
+
+    let from_acc = get_associated_token_address(&payer, &mint);
+    let to_acc = get_associated_token_address(&recipient, &mint);
+    let program_config = find_program_config_address().0;
+
+    let transfer_ix = if let Some(text) = memo {
+        instruction::memo_transfer(
+            accounts::MemoTransfer {
+                mint,
+                from_acc,
+                to_acc,
+                token_2022_program: spl_token_2022::ID,
+                authority: payer,
+                memo_program: spl_memo::ID,
+                program_config,
+            },
+            amount,
+            decimals,
+            TransferMemo::Text { text },
+        )
+    } else if let Ok(fee_config) = mint_state.get_extension::<TransferFeeConfig>() {
+        let fee = fee_config
+            .calculate_epoch_fee(config.rpc.get_epoch_info()?.epoch, amount)
+            .ok_or_else(|| anyhow!("transfer fee overflow"))?;
+
+        instruction::transfer_to(
+            accounts::TransferTo {
+                mint,
+                from_acc,
+                to_acc,
+                token_2022_program: spl_token_2022::ID,
+                authority: payer,
+                program_config,
+            },
+            amount,
+            fee,
+        )
+    } else {
+        let hooked = mint_state
+            .get_extension::<TransferHook>()
+            .is_ok_and(|hook| Option::<Pubkey>::from(hook.program_id).is_some());
+        if hooked {
+            bail!("{mint} has a transfer hook, its extra accounts are not resolved by this tool");
+        }
+
+        instruction::transfer_hook_token(
+            accounts::TransferHookToken {
+                mint,
+                from_acc,
+                to_acc,
+                token_2022_program: spl_token_2022::ID,
+                authority: payer,
+                program_config,
+            },
+            amount,
+            decimals,
+            &[],
+        )
+    };
+
+    let signature = config.send(
+        vec![
+            config.create_associated_token_account(&recipient, &mint),
+            transfer_ix,
+        ],
+        &[],
+    )?;
+
+    println!("Transferred {amount} from {from_acc} to {to_acc}");
+    println!("Signature {signature}");
+
+    Ok(())
+}
+
+pub fn harvest_fees(config: &Config, mint: Pubkey, sources: &[Pubkey]) -> Result<()> {
+    if sources.is_empty() {
+        bail!("pass the token accounts to harvest from");
+    }
+
+    let signature = config.send(
+        vec![instruction::harvest_withheld_token(
+            accounts::HarvestWithheldToken {
+                mint,
+                token_2022_program: spl_token_2022::ID,
+                program_config: find_program_config_address().0,
+            },
+            &writable(sources),
+        )],
+        &[],
+    )?;
+
+    println!("Harvested withheld fees of {} accounts", sources.len());
+    println!("Signature {signature}");
+
+    Ok(())
+}
+
+// Without sources the fees withheld in the mint are withdrawn.
+pub fn withdraw_fees(config: &Config, mint: Pubkey, sources: &[Pubkey]) -> Result<()> {
+    let payer = config.payer();
+    let destination = get_associated_token_address(&payer, &mint);
+    let program_config = find_program_config_address().0;
+
+    let withdraw_ix = if sources.is_empty() {
+        instruction::withdraw_withheld_mint(accounts::WithdrawWithheldMint {
+            mint,
+            destination,
+            token_2022_program: spl_token_2022::ID,
+            authority: payer,
+            program_config,
+        })
+    } else {
+        instruction::withdraw_withheld_account(
+            accounts::WithdrawWithheldAccount {
+                mint,
+                destination,
+                token_2022_program: spl_token_2022::ID,
+                authority: payer,
+                program_config,
+            },
+            &writable(sources),
+        )
+    };
+
+    let signature = config.send(
+        vec![
+            config.create_associated_token_account(&payer, &mint),
+            withdraw_ix,
+        ],
+        &[],
+    )?;
+
+    println!("Withdrew withheld fees to {destination}");
+    println!("Signature {signature}");
+
+    Ok(())
+}
+
+// Tops up the mint rent first when the new value makes the metadata grow.
+pub fn update_metadata(config: &Config, mint: Pubkey, field: &str, value: String) -> Result<()> {
+    let payer = config.payer();
+    let account = config
+        .fetch_account(&mint)?
+        .ok_or_else(|| anyhow!("mint {mint} not found"))?;
+    let mint_state = unpack_mint(&account.data)?;
+    let mut metadata =
+        token_metadata(&mint_state)?.ok_or_else(|| anyhow!("{mint} has no token metadata"))?;
+
+    let (field, meta_field) = match field {
+        "name" => (Field::Name, MetaField::Name),
+        "symbol" => (Field::Symbol, MetaField::Symbol),
+        "uri" => (Field::Uri, MetaField::Uri),
+        key => (Field::Key(key.to_string()), MetaField::Key(key.to_string())),
+    };
+
+    let old_len = metadata.tlv_size_of()?;
+    metadata.update(meta_field, value.clone());
+    let new_len = (account.data.len() + metadata.tlv_size_of()?).saturating_sub(old_len);
+    let rent = config.rpc.get_minimum_balance_for_rent_exemption(new_len)?;
+
+    let mut instructions = Vec::new();
+    if rent > account.lamports {
+        instructions.push(system_instruction::transfer(
+            &payer,
+            &mint,
+            rent - account.lamports,
+        ));
+    }
+    instructions.push(instruction::update_metadata_field(
+        accounts::UpdateMetadataField {
+            mint,
+            payer,
+            update_authority: payer,
+            token_2022_program: spl_token_2022::ID,
+            program_config: find_program_config_address().0,
+        },
+        field,
+        value,
+    ));
+
+    let signature = config.send(instructions, &[])?;
+
+    println!("Updated metadata of {mint}");
+    println!("Signature {signature}");
+
+    Ok(())
+}
+
+pub fn show(config: &Config, address: Pubkey) -> Result<()> {
+    let account = config
+        .fetch_account(&address)?
+        .ok_or_else(|| anyhow!("account {address} not found"))?;
+
+    if account.owner == spl_token_2022::ID {
+        match unpack_mint(&account.data) {
+            Ok(mint) => print_mint(&address, &mint),
+            Err(_) => print_token_account(&address, &unpack_token_account(&account.data)?),
+        }
+    } else if account.owner == token_extension_client::ID {
+        print_program_account(&address, &account.data)
+    } else {
+        bail!(
+            "{address} is owned by {}, not Token-2022 or the token-extension program",
+            account.owner
+        )
+    }
+}
+
+fn print_mint(address: &Pubkey, mint: &MintWithExtensions) -> Result<()> {
+    println!("Mint {address}");
+    println!("  supply {}", mint.base.supply);
+    println!("  decimals {}", mint.base.decimals);
+    println!("  mint authority {}", display(mint.base.mint_authority));
+    println!("  freeze authority {}", display(mint.base.freeze_authority));
+    println!("  extensions {:?}", mint.get_extension_types()?);
+
+    if let Ok(fee_config) = mint.get_extension::<TransferFeeConfig>() {
+        let fee = &fee_config.newer_transfer_fee;
+        println!(
+            "  transfer fee {} bps, max {}, withheld {}",
+            u16::from(fee.transfer_fee_basis_points),
+            u64::from(fee.maximum_fee),
+            u64::from(fee_config.withheld_amount)
+        );
+    }
+    if let Ok(interest) = mint.get_extension::<InterestBearingConfig>() {
+        println!("  interest rate {} bps", i16::from(interest.current_rate));
+    }
+    if let Some(metadata) = token_metadata(mint)? {
+        println!(
+            "  metadata {} ({}) {}",
+            metadata.name, metadata.symbol, metadata.uri
+        );
+        for (key, value) in &metadata.additional_metadata {
+            println!("    {key} = {value}");
+        }
+    }
+
+    Ok(())
+}
+
+fn print_token_account(address: &Pubkey, account: &TokenAccountWithExtensions) -> Result<()> {
+    println!("Token account {address}");
+    println!("  mint {}", account.base.mint);
+    println!("  owner {}", account.base.owner);
+    println!("  amount {}", account.base.amount);
+    println!("  state {:?}", account.base.state);
+    println!("  delegate {}", display(account.base.delegate));
+    println!("  extensions {:?}", account.get_extension_types()?);
+
+    if let Ok(fee_amount) = account.get_extension::<TransferFeeAmount>() {
+        println!("  withheld {}", u64::from(fee_amount.withheld_amount));
+    }
+
+    Ok(())
+}
+
+fn print_program_account(address: &Pubkey, data: &[u8]) -> Result<()> {
+    if let Ok(config) = decode_account::<ProgramConfig>(data) {
+        println!("Program config {address}");
+        println!("  admin {}", config.admin);
+        println!("  pending admin {}", optional(config.pending_admin));
+        println!("  paused {}", config.paused);
+    } else if let Ok(governance) = decode_account::<RateGovernance>(data) {
+        println!("Rate governance {address}");
+        println!("  mint {}", governance.mint);
+        println!("  admin {}", governance.admin);
+        println!(
+            "  rate {} to {} bps, at most {} bps per {}s period",
+            governance.min_rate,
+            governance.max_rate,
+            governance.max_change_per_period,
+            governance.period
+        );
+        println!(
+            "  period started {}, changed {} bps",
+            governance.period_start, governance.period_change
+        );
+        println!("  timelock {}s", governance.timelock);
+        println!(
+            "  pending rate {}, proposed {}",
+            optional(governance.pending_rate),
+            governance.proposed_at
+        );
+    } else if let Ok(kyc) = decode_account::<KycConfig>(data) {
+        println!("KYC config {address}");
+        println!("  mint {}", kyc.mint);
+        println!("  compliance officer {}", kyc.compliance_officer);
+    } else if let Ok(request) = decode_account::<AccessRequest>(data) {
+        println!("Access request {address}");
+        println!("  mint {}", request.mint);
+        println!("  token account {}", request.token_account);
+        println!("  wallet {}", request.wallet);
+        println!("  status {:?}", request.status);
+        println!(
+            "  requested {}, updated {}",
+            request.requested_at, request.updated_at
+        );
+    } else if let Ok(record) = decode_account::<FreezeRecord>(data) {
+        println!("Freeze record {address}");
+        println!("  mint {}", record.mint);
+        println!("  token account {}", record.token_account);
+        println!("  frozen {}", record.frozen);
+        println!("  reason code {}", record.reason_code);
+        println!(
+            "  updated by {} at {}",
+            record.updated_by, record.updated_at
+        );
+    } else if let Ok(governance) = decode_account::<DelegateGovernance>(data) {
+        println!("Delegate governance {address}");
+        println!("  mint {}", governance.mint);
+        println!(
+            "  threshold {} of {}",
+            governance.threshold,
+            governance.signers.len()
+        );
+        for signer in &governance.signers {
+            println!("    {signer}");
+        }
+        println!("  proposals {}", governance.proposal_count);
+    } else if let Ok(proposal) = decode_account::<DelegateProposal>(data) {
+        println!("Delegate proposal {address}");
+        println!("  governance {}", proposal.governance);
+        println!("  id {}", proposal.id);
+        println!("  action {:?}", proposal.action);
+        println!("  from {}", proposal.from);
+        println!("  treasury {}", optional(proposal.treasury));
+        println!("  amount {}", proposal.amount);
+        println!("  reason {}", proposal.reason);
+        println!("  proposer {}", proposal.proposer);
+        println!(
+            "  status {:?}, expires {}",
+            proposal.status, proposal.expires_at
+        );
+        println!("  approvals {}", proposal.approvals.len());
+        for approver in &proposal.approvals {
+            println!("    {approver}");
+        }
+    } else {
+        bail!("unknown token-extension program account");
+    }
+
+    Ok(())
+}
+
+fn display(key: COption<Pubkey>) -> String {
+    optional(Option::<Pubkey>::from(key))
+}
+
+fn optional<T: Display>(value: Option<T>) -> String {
+    value.map_or_else(|| "none".to_string(), |value| value.to_string())
+}
+
+fn writable(keys: &[Pubkey]) -> Vec<AccountMeta> {
+    keys.iter()
+        .map(|key| AccountMeta::new(*key, false))
+        .collect()
+}
+
+pub fn read_keypair(path: &Path) -> Result<Keypair> {
+    read_keypair_file(path)
+        .map_err(|err| anyhow!("{err}"))
+        .with_context(|| format!("reading keypair {}", path.display()))
+}
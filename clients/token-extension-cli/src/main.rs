@@ -0,0 +1,199 @@
+//! Command-line tool creating token-extension mints from a TOML or JSON spec and driving
+//! the program against an RPC endpoint, usually a local test validator.
+
+mod commands;
+mod mint;
+mod spec;
+
+use {
+    anyhow::{anyhow, bail, Context, Result},
+    commands::Config,
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey},
+    std::{env, path::PathBuf, str::FromStr},
+};
+
+const DEFAULT_URL: &str = "http://127.0.0.1:8899";
+
+const USAGE: &str = "\
+Usage: token-extension-cli [OPTIONS] <COMMAND>
+
+Commands:
+  create-mint <SPEC> [--mint-keypair <PATH>]       Create a mint from a TOML or JSON spec
+  mint-to <MINT> <AMOUNT>                          Mint to the keypair's associated token account
+  transfer <MINT> <AMOUNT> <RECIPIENT> [--memo <TEXT>]
+                                                   Transfer from the keypair's associated token account
+  harvest-fees <MINT> <ACCOUNT>...                 Harvest withheld fees of token accounts to the mint
+  withdraw-fees <MINT> [ACCOUNT]...                Withdraw withheld fees of the mint, or of the accounts given
+  update-metadata <MINT> <FIELD> <VALUE>           Update name, symbol, uri or an additional field
+  show <ADDRESS>                                   Print a mint, token account or program account
+
+Options:
+  --url <URL>                     RPC endpoint [default: http://127.0.0.1:8899]
+  --keypair <PATH>                Payer and authority keypair [default: ~/.config/solana/id.json]
+  --compute-unit-price <LAMPORTS> Priority fee in micro-lamports per compute unit
+  -h, --help                      Print this help
+";
+
+fn main() {
+    if let Err(err) = run(env::args().skip(1).collect()) {
+        eprintln!("Error: {err:#}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: Vec<String>) -> Result<()> {
+    let mut args = Args::new(args);
+
+    if args.flag("-h") || args.flag("--help") || args.is_empty() {
+        print!("{USAGE}");
+        return Ok(());
+    }
+
+    let url = args
+        .option("--url")?
+        .unwrap_or_else(|| DEFAULT_URL.to_string());
+    let keypair = match args.option("--keypair")? {
+        Some(path) => PathBuf::from(path),
+        None => default_keypair_path()?,
+    };
+    let compute_unit_price = args
+        .option("--compute-unit-price")?
+        .map(|price| parse::<u64>(&price, "compute unit price"))
+        .transpose()?;
+
+    let command = args.next("command")?;
+
+    // Command options are taken out before the positional arguments are read.
+    let mint_keypair = match command.as_str() {
+        "create-mint" => args.option("--mint-keypair")?.map(PathBuf::from),
+        _ => None,
+    };
+    let memo = match command.as_str() {
+        "transfer" => args.option("--memo")?,
+        _ => None,
+    };
+
+    let config = Config {
+        rpc: RpcClient::new_with_commitment(url, CommitmentConfig::confirmed()),
+        keypair: commands::read_keypair(&keypair)?,
+        compute_unit_price,
+    };
+
+    match command.as_str() {
+        "create-mint" => {
+            let spec = PathBuf::from(args.next("spec")?);
+            args.finish()?;
+            commands::create_mint(&config, &spec, mint_keypair.as_deref())
+        }
+        "mint-to" => {
+            let mint = args.pubkey("mint")?;
+            let amount = parse(&args.next("amount")?, "amount")?;
+            args.finish()?;
+            commands::mint_to(&config, mint, amount)
+        }
+        "transfer" => {
+            let mint = args.pubkey("mint")?;
+            let amount = parse(&args.next("amount")?, "amount")?;
+            let recipient = args.pubkey("recipient")?;
+            args.finish()?;
+            commands::transfer(&config, mint, amount, recipient, memo)
+        }
+        "harvest-fees" => {
+            let mint = args.pubkey("mint")?;
+            let sources = args.pubkeys()?;
+            commands::harvest_fees(&config, mint, &sources)
+        }
+        "withdraw-fees" => {
+            let mint = args.pubkey("mint")?;
+            let sources = args.pubkeys()?;
+            commands::withdraw_fees(&config, mint, &sources)
+        }
+        "update-metadata" => {
+            let mint = args.pubkey("mint")?;
+            let field = args.next("field")?;
+            let value = args.next("value")?;
+            args.finish()?;
+            commands::update_metadata(&config, mint, &field, value)
+        }
+        "show" => {
+            let address = args.pubkey("address")?;
+            args.finish()?;
+            commands::show(&config, address)
+        }
+        other => bail!("unknown command {other}, see --help"),
+    }
+}
+
+struct Args(Vec<String>);
+
+impl Args {
+    fn new(args: Vec<String>) -> Self {
+        Self(args)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn flag(&mut self, name: &str) -> bool {
+        let found = self.0.iter().any(|arg| arg == name);
+        self.0.retain(|arg| arg != name);
+        found
+    }
+
+    // Removes `name <value>` or `name=<value>` and returns the value.
+    fn option(&mut self, name: &str) -> Result<Option<String>> {
+        let prefix = format!("{name}=");
+
+        let Some(index) = self
+            .0
+            .iter()
+            .position(|arg| arg == name || arg.starts_with(&prefix))
+        else {
+            return Ok(None);
+        };
+
+        let arg = self.0.remove(index);
+        if let Some(value) = arg.strip_prefix(&prefix) {
+            return Ok(Some(value.to_string()));
+        }
+        if index >= self.0.len() {
+            bail!("{name} needs a value");
+        }
+
+        Ok(Some(self.0.remove(index)))
+    }
+
+    fn next(&mut self, name: &str) -> Result<String> {
+        if self.0.is_empty() {
+            bail!("missing <{}>, see --help", name.to_uppercase());
+        }
+
+        Ok(self.0.remove(0))
+    }
+
+    fn pubkey(&mut self, name: &str) -> Result<Pubkey> {
+        parse(&self.next(name)?, name)
+    }
+
+    fn pubkeys(&mut self) -> Result<Vec<Pubkey>> {
+        self.0.drain(..).map(|arg| parse(&arg, "account")).collect()
+    }
+
+    fn finish(&self) -> Result<()> {
+        match self.0.first() {
+            Some(arg) => bail!("unexpected argument {arg}, see --help"),
+            None => Ok(()),
+        }
+    }
+}
+
+fn parse<T: FromStr>(value: &str, name: &str) -> Result<T> {
+    value.parse().map_err(|_| anyhow!("invalid {name} {value}"))
+}
+
+fn default_keypair_path() -> Result<PathBuf> {
+    let home = env::var("HOME").context("HOME is not set, pass --keypair")?;
+    Ok(PathBuf::from(home).join(".config/solana/id.json"))
+}
@@ -0,0 +1,339 @@
+//! Token-2022 instructions creating a mint with every extension of a spec in one transaction.
+//!
+//! The program creates mints with one extension per instruction, so mixing extensions goes
+//! straight to Token-2022.
+
+use {
+    crate::spec::{AccountStateSpec, MintSpec},
+    anyhow::{bail, Result},
+    solana_sdk::{instruction::Instruction, pubkey::Pubkey, system_instruction},
+    spl_token_2022::{
+        extension::{
+            default_account_state::instruction::initialize_default_account_state,
+            interest_bearing_mint, metadata_pointer,
+            transfer_fee::instruction::initialize_transfer_fee_config, transfer_hook,
+            ExtensionType,
+        },
+        instruction::{
+            initialize_mint2, initialize_mint_close_authority, initialize_non_transferable_mint,
+            initialize_permanent_delegate,
+        },
+        state::{AccountState, Mint},
+    },
+    spl_token_metadata_interface::{
+        instruction::{initialize as initialize_metadata, update_field},
+        state::{Field, TokenMetadata},
+    },
+};
+
+pub struct MintPlan<'a> {
+    spec: &'a MintSpec,
+    mint: Pubkey,
+    mint_authority: Pubkey,
+}
+
+impl<'a> MintPlan<'a> {
+    // `signer` pays for the mint and is the default mint authority.
+    pub fn new(spec: &'a MintSpec, mint: Pubkey, signer: &Pubkey) -> Result<Self> {
+        let mint_authority = spec.mint_authority.unwrap_or(*signer);
+
+        if spec.metadata.is_some() && mint_authority != *signer {
+            bail!("metadata is initialized by the mint authority, which must be the CLI keypair");
+        }
+
+        let frozen = spec
+            .extensions
+            .default_account_state
+            .as_ref()
+            .is_some_and(|default| default.state == AccountStateSpec::Frozen);
+        if frozen && spec.freeze_authority.is_none() {
+            bail!("a frozen default account state needs a freeze authority");
+        }
+
+        Ok(Self {
+            spec,
+            mint,
+            mint_authority,
+        })
+    }
+
+    pub fn extension_types(&self) -> Vec<ExtensionType> {
+        let extensions = &self.spec.extensions;
+
+        [
+            (
+                extensions.transfer_fee.is_some(),
+                ExtensionType::TransferFeeConfig,
+            ),
+            (
+                extensions.interest_bearing.is_some(),
+                ExtensionType::InterestBearingConfig,
+            ),
+            (
+                extensions.default_account_state.is_some(),
+                ExtensionType::DefaultAccountState,
+            ),
+            (
+                extensions.mint_close_authority.is_some(),
+                ExtensionType::MintCloseAuthority,
+            ),
+            (
+                extensions.permanent_delegate.is_some(),
+                ExtensionType::PermanentDelegate,
+            ),
+            (
+                extensions.non_transferable.is_some(),
+                ExtensionType::NonTransferable,
+            ),
+            (
+                extensions.transfer_hook.is_some(),
+                ExtensionType::TransferHook,
+            ),
+            (self.spec.metadata.is_some(), ExtensionType::MetadataPointer),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, extension)| enabled.then_some(extension))
+        .collect()
+    }
+
+    // Size the mint account is created with, metadata is appended by Token-2022 later on.
+    pub fn mint_len(&self) -> Result<usize> {
+        Ok(ExtensionType::try_calculate_account_len::<Mint>(
+            &self.extension_types(),
+        )?)
+    }
+
+    // Size of the account once the metadata and its additional fields are written.
+    pub fn rent_len(&self) -> Result<usize> {
+        let Some(metadata) = &self.spec.metadata else {
+            return self.mint_len();
+        };
+
+        let token_metadata = TokenMetadata {
+            mint: self.mint,
+            name: metadata.name.clone(),
+            symbol: metadata.symbol.clone(),
+            uri: metadata.uri.clone(),
+            additional_metadata: metadata
+                .additional
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            ..Default::default()
+        };
+
+        Ok(self.mint_len()? + token_metadata.tlv_size_of()?)
+    }
+
+    pub fn instructions(&self, payer: &Pubkey, lamports: u64) -> Result<Vec<Instruction>> {
+        let token_program = &spl_token_2022::ID;
+        let mint = &self.mint;
+        let authority = self.mint_authority;
+        let extensions = &self.spec.extensions;
+
+        let mut instructions = vec![system_instruction::create_account(
+            payer,
+            mint,
+            lamports,
+            self.mint_len()? as u64,
+            token_program,
+        )];
+
+        // Extensions are initialized before the mint itself.
+        if let Some(fee) = &extensions.transfer_fee {
+            instructions.push(initialize_transfer_fee_config(
+                token_program,
+                mint,
+                Some(&fee.config_authority.unwrap_or(authority)),
+                Some(&fee.withdraw_authority.unwrap_or(authority)),
+                fee.basis_points,
+                fee.maximum_fee,
+            )?);
+        }
+        if let Some(interest) = &extensions.interest_bearing {
+            instructions.push(interest_bearing_mint::instruction::initialize(
+                token_program,
+                mint,
+                Some(interest.rate_authority.unwrap_or(authority)),
+                interest.rate,
+            )?);
+        }
+        if let Some(default) = &extensions.default_account_state {
+            let state = match default.state {
+                AccountStateSpec::Initialized => AccountState::Initialized,
+                AccountStateSpec::Frozen => AccountState::Frozen,
+            };
+            instructions.push(initialize_default_account_state(
+                token_program,
+                mint,
+                &state,
+            )?);
+        }
+        if let Some(close) = &extensions.mint_close_authority {
+            instructions.push(initialize_mint_close_authority(
+                token_program,
+                mint,
+                Some(&close.close_authority.unwrap_or(authority)),
+            )?);
+        }
+        if let Some(delegate) = &extensions.permanent_delegate {
+            instructions.push(initialize_permanent_delegate(
+                token_program,
+                mint,
+                &delegate.delegate.unwrap_or(authority),
+            )?);
+        }
+        if extensions.non_transferable.is_some() {
+            instructions.push(initialize_non_transferable_mint(token_program, mint)?);
+        }
+        if let Some(hook) = &extensions.transfer_hook {
+            instructions.push(transfer_hook::instruction::initialize(
+                token_program,
+                mint,
+                Some(hook.authority.unwrap_or(authority)),
+                Some(hook.program_id),
+            )?);
+        }
+        if self.spec.metadata.is_some() {
+            instructions.push(metadata_pointer::instruction::initialize(
+                token_program,
+                mint,
+                Some(authority),
+                Some(*mint),
+            )?);
+        }
+
+        instructions.push(initialize_mint2(
+            token_program,
+            mint,
+            &authority,
+            self.spec.freeze_authority.as_ref(),
+            self.spec.decimals,
+        )?);
+
+        if let Some(metadata) = &self.spec.metadata {
+            instructions.push(initialize_metadata(
+                token_program,
+                mint,
+                &authority,
+                mint,
+                &authority,
+                metadata.name.clone(),
+                metadata.symbol.clone(),
+                metadata.uri.clone(),
+            ));
+
+            for (key, value) in &metadata.additional {
+                instructions.push(update_field(
+                    token_program,
+                    mint,
+                    &authority,
+                    Field::Key(key.clone()),
+                    value.clone(),
+                ));
+            }
+        }
+
+        Ok(instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::path::Path};
+
+    fn example(name: &str) -> MintSpec {
+        MintSpec::load(
+            &Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("examples")
+                .join(name),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn frozen_default_state_needs_a_freeze_authority() {
+        let signer = Pubkey::new_unique();
+        let spec: MintSpec =
+            toml::from_str("decimals = 0\n[extensions.default_account_state]\nstate = \"frozen\"")
+                .unwrap();
+
+        let err = MintPlan::new(&spec, Pubkey::new_unique(), &signer)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "a frozen default account state needs a freeze authority"
+        );
+
+        let spec: MintSpec = toml::from_str(&format!(
+            "decimals = 0\nfreeze_authority = \"{signer}\"\n[extensions.default_account_state]\nstate = \"frozen\""
+        ))
+        .unwrap();
+        assert!(MintPlan::new(&spec, Pubkey::new_unique(), &signer).is_ok());
+    }
+
+    #[test]
+    fn metadata_needs_the_signer_as_mint_authority() {
+        let spec: MintSpec = toml::from_str(&format!(
+            "decimals = 0\nmint_authority = \"{}\"\n[metadata]\nname = \"a\"\nsymbol = \"b\"\nuri = \"c\"",
+            Pubkey::new_unique()
+        ))
+        .unwrap();
+
+        assert!(MintPlan::new(&spec, Pubkey::new_unique(), &Pubkey::new_unique()).is_err());
+    }
+
+    #[test]
+    fn sizes_the_toml_example() {
+        let spec = example("mint.toml");
+        let plan = MintPlan::new(&spec, Pubkey::new_unique(), &Pubkey::new_unique()).unwrap();
+
+        assert_eq!(
+            plan.extension_types(),
+            [
+                ExtensionType::TransferFeeConfig,
+                ExtensionType::InterestBearingConfig,
+                ExtensionType::MintCloseAuthority,
+                ExtensionType::MetadataPointer,
+            ]
+        );
+        // 166 byte base and account type, then each extension with its 4 byte TLV header.
+        assert_eq!(plan.mint_len().unwrap(), 166 + 112 + 56 + 36 + 68);
+        // Metadata adds a 12 byte TLV header, both authorities and the length prefixed strings.
+        assert_eq!(
+            plan.rent_len().unwrap(),
+            438 + 12 + 64 + (4 + 13) + (4 + 3) + (4 + 30) + 4 + (4 + 6) + (4 + 12)
+        );
+    }
+
+    #[test]
+    fn sizes_the_json_example() {
+        let spec = example("soulbound.json");
+        let plan = MintPlan::new(&spec, Pubkey::new_unique(), &Pubkey::new_unique()).unwrap();
+
+        assert_eq!(
+            plan.extension_types(),
+            [
+                ExtensionType::PermanentDelegate,
+                ExtensionType::NonTransferable,
+                ExtensionType::MetadataPointer,
+            ]
+        );
+        assert_eq!(plan.mint_len().unwrap(), 166 + 36 + 4 + 68);
+        assert_eq!(
+            plan.rent_len().unwrap(),
+            274 + 12 + 64 + (4 + 10) + (4 + 3) + (4 + 35) + 4
+        );
+    }
+
+    #[test]
+    fn sizes_a_plain_mint() {
+        let spec: MintSpec = toml::from_str("decimals = 9").unwrap();
+        let plan = MintPlan::new(&spec, Pubkey::new_unique(), &Pubkey::new_unique()).unwrap();
+
+        assert!(plan.extension_types().is_empty());
+        assert_eq!(plan.mint_len().unwrap(), 82);
+        assert_eq!(plan.rent_len().unwrap(), 82);
+    }
+}
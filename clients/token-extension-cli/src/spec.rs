@@ -0,0 +1,211 @@
+//! Declarative mint spec, read from a TOML or JSON file.
+
+use {
+    anyhow::{bail, Context, Result},
+    serde::{de, Deserialize, Deserializer},
+    solana_sdk::pubkey::Pubkey,
+    std::{collections::BTreeMap, fs, path::Path},
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MintSpec {
+    pub decimals: u8,
+    /// Defaults to the CLI keypair, which must stay the mint authority when metadata is set
+    #[serde(default, deserialize_with = "optional_pubkey")]
+    pub mint_authority: Option<Pubkey>,
+    #[serde(default, deserialize_with = "optional_pubkey")]
+    pub freeze_authority: Option<Pubkey>,
+    #[serde(default)]
+    pub extensions: ExtensionsSpec,
+    pub metadata: Option<MetadataSpec>,
+}
+
+// Authorities left out default to the mint authority.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExtensionsSpec {
+    pub transfer_fee: Option<TransferFeeSpec>,
+    pub interest_bearing: Option<InterestBearingSpec>,
+    pub default_account_state: Option<DefaultAccountStateSpec>,
+    pub mint_close_authority: Option<MintCloseAuthoritySpec>,
+    pub permanent_delegate: Option<PermanentDelegateSpec>,
+    pub non_transferable: Option<NonTransferableSpec>,
+    pub transfer_hook: Option<TransferHookSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransferFeeSpec {
+    pub basis_points: u16,
+    pub maximum_fee: u64,
+    #[serde(default, deserialize_with = "optional_pubkey")]
+    pub config_authority: Option<Pubkey>,
+    #[serde(default, deserialize_with = "optional_pubkey")]
+    pub withdraw_authority: Option<Pubkey>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InterestBearingSpec {
+    /// Rate in basis points
+    pub rate: i16,
+    #[serde(default, deserialize_with = "optional_pubkey")]
+    pub rate_authority: Option<Pubkey>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DefaultAccountStateSpec {
+    pub state: AccountStateSpec,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccountStateSpec {
+    Initialized,
+    Frozen,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MintCloseAuthoritySpec {
+    #[serde(default, deserialize_with = "optional_pubkey")]
+    pub close_authority: Option<Pubkey>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PermanentDelegateSpec {
+    #[serde(default, deserialize_with = "optional_pubkey")]
+    pub delegate: Option<Pubkey>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NonTransferableSpec {}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransferHookSpec {
+    #[serde(deserialize_with = "pubkey")]
+    pub program_id: Pubkey,
+    #[serde(default, deserialize_with = "optional_pubkey")]
+    pub authority: Option<Pubkey>,
+}
+
+// Stored in the mint itself through a metadata pointer to the mint.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MetadataSpec {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    #[serde(default)]
+    pub additional: BTreeMap<String, String>,
+}
+
+impl MintSpec {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+
+        let spec = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => toml::from_str(&text)?,
+            Some("json") => serde_json::from_str(&text)?,
+            _ => bail!("{} is neither a .toml nor a .json file", path.display()),
+        };
+
+        Ok(spec)
+    }
+}
+
+fn pubkey<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(de::Error::custom)
+}
+
+fn optional_pubkey<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Pubkey>, D::Error> {
+    pubkey(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(name: &str) -> MintSpec {
+        MintSpec::load(
+            &Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("examples")
+                .join(name),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn loads_the_toml_example() {
+        let spec = example("mint.toml");
+
+        assert_eq!(spec.decimals, 6);
+        assert_eq!(spec.mint_authority, None);
+        assert_eq!(spec.freeze_authority, None);
+
+        let fee = spec.extensions.transfer_fee.unwrap();
+        assert_eq!(fee.basis_points, 100);
+        assert_eq!(fee.maximum_fee, 5_000_000);
+        assert_eq!(fee.config_authority, None);
+        assert_eq!(spec.extensions.interest_bearing.unwrap().rate, 250);
+        assert_eq!(
+            spec.extensions
+                .mint_close_authority
+                .unwrap()
+                .close_authority,
+            None
+        );
+        assert!(spec.extensions.non_transferable.is_none());
+
+        let metadata = spec.metadata.unwrap();
+        assert_eq!(metadata.name, "Example Token");
+        assert_eq!(metadata.symbol, "EXT");
+        assert_eq!(metadata.additional["issuer"], "Example Labs");
+    }
+
+    #[test]
+    fn loads_the_json_example() {
+        let spec = example("soulbound.json");
+
+        assert_eq!(spec.decimals, 0);
+        assert!(spec.extensions.non_transferable.is_some());
+        assert_eq!(spec.extensions.permanent_delegate.unwrap().delegate, None);
+        assert!(spec.extensions.transfer_fee.is_none());
+
+        let metadata = spec.metadata.unwrap();
+        assert_eq!(metadata.name, "Membership");
+        assert!(metadata.additional.is_empty());
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        let top_level = toml::from_str::<MintSpec>("decimals = 6\nsupply = 100").unwrap_err();
+        assert!(top_level.to_string().contains("unknown field `supply`"));
+
+        let extension =
+            toml::from_str::<MintSpec>("decimals = 6\n[extensions.confidential_transfer]")
+                .unwrap_err();
+        assert!(extension
+            .to_string()
+            .contains("unknown field `confidential_transfer`"));
+
+        let nested = serde_json::from_str::<MintSpec>(
+            r#"{ "decimals": 6, "extensions": { "transfer_fee": { "basis_points": 1, "maximum_fee": 1, "bps": 1 } } }"#,
+        )
+        .unwrap_err();
+        assert!(nested.to_string().contains("unknown field `bps`"));
+    }
+
+    #[test]
+    fn rejects_invalid_pubkeys() {
+        assert!(toml::from_str::<MintSpec>("decimals = 6\nfreeze_authority = \"nope\"").is_err());
+    }
+}
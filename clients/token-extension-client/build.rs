@@ -0,0 +1,290 @@
+// Generates the program bindings from the IDL in `idl/`: constants, types, program owned
+// accounts, one accounts struct per instruction and the instruction data.
+use {
+    serde_json::Value,
+    sha2::{Digest, Sha256},
+    std::{env, fmt::Write, fs, path::Path},
+};
+
+const IDL_PATH: &str = "idl/token_extension.json";
+
+fn main() {
+    println!("cargo:rerun-if-changed={IDL_PATH}");
+
+    let idl: Value = serde_json::from_str(&fs::read_to_string(IDL_PATH).expect("read the IDL"))
+        .expect("parse the IDL");
+
+    let mut out = String::new();
+    constants(&mut out, &idl);
+    types(&mut out, &idl);
+    state(&mut out, &idl);
+    accounts(&mut out, &idl);
+    instruction(&mut out, &idl);
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("token_extension.rs");
+    fs::write(path, out).expect("write the bindings");
+}
+
+fn list<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value[key].as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+fn name(value: &Value) -> &str {
+    value["name"].as_str().expect("named IDL item")
+}
+
+// The IDL names fields and instructions in camelCase, `token2022Program` included.
+fn snake_case(camel: &str) -> String {
+    let mut snake = String::new();
+    let mut previous: Option<char> = None;
+    for c in camel.chars() {
+        let boundary = match previous {
+            Some(p) if c.is_ascii_uppercase() => p.is_ascii_alphanumeric(),
+            Some(p) if c.is_ascii_digit() => p.is_ascii_alphabetic(),
+            _ => false,
+        };
+        if boundary {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+        previous = Some(c);
+    }
+    snake
+}
+
+fn pascal_case(camel: &str) -> String {
+    let mut chars = camel.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+fn discriminator(preimage: &str) -> String {
+    let hash = Sha256::digest(preimage.as_bytes());
+    format!("{:?}", &hash[..8])
+}
+
+fn rust_type(ty: &Value) -> String {
+    match ty {
+        Value::String(primitive) => match primitive.as_str() {
+            "publicKey" => "Pubkey".to_string(),
+            "string" => "String".to_string(),
+            "bytes" => "Vec<u8>".to_string(),
+            primitive => primitive.to_string(),
+        },
+        Value::Object(composite) => {
+            let (kind, inner) = composite.iter().next().expect("composite IDL type");
+            match kind.as_str() {
+                "vec" => format!("Vec<{}>", rust_type(inner)),
+                "option" => format!("Option<{}>", rust_type(inner)),
+                "array" => format!("[{}; {}]", rust_type(&inner[0]), inner[1]),
+                "defined" => format!("super::types::{}", inner.as_str().unwrap()),
+                kind => panic!("unsupported IDL type {kind}"),
+            }
+        }
+        ty => panic!("unsupported IDL type {ty}"),
+    }
+}
+
+// Whether the type is made of fixed size primitives only, so it can derive `Copy`.
+fn is_copy(ty: &Value) -> bool {
+    match ty {
+        Value::String(primitive) => !matches!(primitive.as_str(), "string" | "bytes"),
+        Value::Object(composite) => match composite.iter().next() {
+            Some((kind, inner)) if kind == "option" => is_copy(inner),
+            Some((kind, inner)) if kind == "array" => is_copy(&inner[0]),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn docs(out: &mut String, item: &Value, indent: &str) {
+    for line in list(item, "docs") {
+        writeln!(out, "{indent}/// {}", line.as_str().unwrap().trim()).unwrap();
+    }
+}
+
+fn fields(out: &mut String, fields: &[Value], indent: &str) {
+    for field in fields {
+        docs(out, field, indent);
+        writeln!(
+            out,
+            "{indent}pub {}: {},",
+            snake_case(name(field)),
+            rust_type(&field["type"])
+        )
+        .unwrap();
+    }
+}
+
+fn constants(out: &mut String, idl: &Value) {
+    out.push_str("pub mod constants {\n");
+    for constant in list(idl, "constants") {
+        let value = constant["value"].as_str().unwrap();
+        let ty = match constant["type"].as_str().unwrap() {
+            "bytes" => "&[u8]",
+            "string" => "&str",
+            ty => ty,
+        };
+        let value = if ty == "&[u8]" {
+            format!("&{value}")
+        } else {
+            value.to_string()
+        };
+        writeln!(out, "    pub const {}: {ty} = {value};", name(constant)).unwrap();
+    }
+    out.push_str("}\n\n");
+}
+
+fn types(out: &mut String, idl: &Value) {
+    out.push_str("pub mod types {\n    use anchor_lang::prelude::*;\n");
+    for ty in list(idl, "types") {
+        let def = &ty["type"];
+        out.push('\n');
+        docs(out, ty, "    ");
+        if def["kind"] == "struct" {
+            let copy = list(def, "fields").iter().all(|f| is_copy(&f["type"]));
+            writeln!(
+                out,
+                "    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq{})]",
+                if copy { ", Copy, Default" } else { "" }
+            )
+            .unwrap();
+            writeln!(out, "    pub struct {} {{", name(ty)).unwrap();
+            fields(out, list(def, "fields"), "        ");
+            out.push_str("    }\n");
+        } else {
+            let unit = list(def, "variants")
+                .iter()
+                .all(|v| v.get("fields").is_none());
+            writeln!(
+                out,
+                "    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq{})]",
+                if unit { ", Copy, Eq" } else { "" }
+            )
+            .unwrap();
+            writeln!(out, "    pub enum {} {{", name(ty)).unwrap();
+            for variant in list(def, "variants") {
+                docs(out, variant, "        ");
+                match list(variant, "fields") {
+                    [] => writeln!(out, "        {},", name(variant)).unwrap(),
+                    named @ [Value::Object(_), ..] => {
+                        writeln!(out, "        {} {{", name(variant)).unwrap();
+                        for field in named {
+                            docs(out, field, "            ");
+                            writeln!(
+                                out,
+                                "            {}: {},",
+                                snake_case(name(field)),
+                                rust_type(&field["type"])
+                            )
+                            .unwrap();
+                        }
+                        out.push_str("        },\n");
+                    }
+                    tuple => {
+                        let types: Vec<String> = tuple.iter().map(rust_type).collect();
+                        writeln!(out, "        {}({}),", name(variant), types.join(", ")).unwrap();
+                    }
+                }
+            }
+            out.push_str("    }\n");
+        }
+    }
+    out.push_str("}\n\n");
+}
+
+// `#[account]` adds the discriminator, (de)serialization and the program as owner.
+fn state(out: &mut String, idl: &Value) {
+    out.push_str("pub mod state {\n    use anchor_lang::prelude::*;\n");
+    for account in list(idl, "accounts") {
+        out.push('\n');
+        docs(out, account, "    ");
+        out.push_str("    #[account]\n    #[derive(Debug, PartialEq)]\n");
+        writeln!(out, "    pub struct {} {{", name(account)).unwrap();
+        fields(out, list(&account["type"], "fields"), "        ");
+        out.push_str("    }\n");
+    }
+    out.push_str("}\n\n");
+}
+
+// Optional accounts left out are passed as the program id, as Anchor expects.
+fn accounts(out: &mut String, idl: &Value) {
+    out.push_str("pub mod accounts {\n    use anchor_lang::{prelude::*, solana_program::instruction::AccountMeta};\n");
+    for ix in list(idl, "instructions") {
+        let accounts = list(ix, "accounts");
+        let struct_name = pascal_case(name(ix));
+
+        writeln!(
+            out,
+            "\n    #[derive(Clone, Copy, Debug)]\n    pub struct {struct_name} {{"
+        )
+        .unwrap();
+        for account in accounts {
+            docs(out, account, "        ");
+            let ty = if account["isOptional"] == true {
+                "Option<Pubkey>"
+            } else {
+                "Pubkey"
+            };
+            writeln!(out, "        pub {}: {ty},", snake_case(name(account))).unwrap();
+        }
+        out.push_str("    }\n");
+
+        writeln!(
+            out,
+            "\n    impl ToAccountMetas for {struct_name} {{\n        fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {{\n            vec!["
+        )
+        .unwrap();
+        for account in accounts {
+            let field = snake_case(name(account));
+            let signer = account["isSigner"] == true;
+            let meta = |key: &str| {
+                if account["isMut"] == true {
+                    format!("AccountMeta::new({key}, {signer})")
+                } else {
+                    format!("AccountMeta::new_readonly({key}, {signer})")
+                }
+            };
+            if account["isOptional"] == true {
+                writeln!(
+                    out,
+                    "                match self.{field} {{\n                    Some(key) => {},\n                    None => AccountMeta::new_readonly(crate::ID, false),\n                }},",
+                    meta("key")
+                )
+                .unwrap();
+            } else {
+                writeln!(out, "                {},", meta(&format!("self.{field}"))).unwrap();
+            }
+        }
+        out.push_str("            ]\n        }\n    }\n");
+    }
+    out.push_str("}\n\n");
+}
+
+fn instruction(out: &mut String, idl: &Value) {
+    out.push_str("pub mod instruction {\n    use anchor_lang::{prelude::*, Discriminator, InstructionData};\n");
+    for ix in list(idl, "instructions") {
+        let struct_name = pascal_case(name(ix));
+        let args = list(ix, "args");
+
+        out.push_str("\n    #[derive(AnchorSerialize)]\n");
+        if args.is_empty() {
+            writeln!(out, "    pub struct {struct_name};").unwrap();
+        } else {
+            writeln!(out, "    pub struct {struct_name} {{").unwrap();
+            fields(out, args, "        ");
+            out.push_str("    }\n");
+        }
+
+        writeln!(
+            out,
+            "\n    impl Discriminator for {struct_name} {{\n        const DISCRIMINATOR: [u8; 8] = {};\n    }}\n\n    impl InstructionData for {struct_name} {{}}",
+            discriminator(&format!("global:{}", snake_case(name(ix))))
+        )
+        .unwrap();
+    }
+    out.push_str("}\n");
+}
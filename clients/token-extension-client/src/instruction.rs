@@ -0,0 +1,758 @@
+//! Instruction builders, one per instruction of the program.
+//!
+//! Builders take the accounts struct generated for the instruction and its arguments.
+//! PDAs such as the program config come from [`crate::pda`].
+//!
+//! Token-2022 only toggles CPI Guard from a top level instruction, so the builders that turn
+//! it on or off return the program instruction followed by the Token-2022 toggle. Send them
+//! in that order in the same transaction, the program checks the toggle comes next.
+
+use {
+    crate::{
+        accounts, generated::instruction as ix, AccountExtension, AuthorityKind, CredentialClaim,
+        DefaultState, DelegateAction, Field, TokenAccountOptions, TransferMemo,
+    },
+    anchor_lang::{
+        prelude::Pubkey,
+        solana_program::instruction::{AccountMeta, Instruction},
+        InstructionData, ToAccountMetas,
+    },
+    spl_token_2022::extension::cpi_guard::instruction::{
+        disable_cpi_guard as disable, enable_cpi_guard as enable,
+    },
+};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    build_with_remaining(accounts, data, &[])
+}
+
+fn build_with_remaining(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    let mut metas = accounts.to_account_metas(None);
+    metas.extend_from_slice(remaining_accounts);
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: metas,
+        data: data.data(),
+    }
+}
+
+// The program instruction followed by the Token-2022 CPI Guard toggle it requires.
+fn with_cpi_guard_toggle(
+    program_ix: Instruction,
+    token_account: &Pubkey,
+    owner: &Pubkey,
+    enable_guard: bool,
+) -> Vec<Instruction> {
+    let toggle = if enable_guard { enable } else { disable };
+    let toggle_ix = toggle(&spl_token_2022::ID, token_account, owner, &[])
+        .expect("Token-2022 builds the toggle for its own program id");
+
+    vec![program_ix, toggle_ix]
+}
+
+/// Create the program config PDA, the upgrade authority becomes its admin.
+pub fn initialize_program_config(accounts: accounts::InitializeProgramConfig) -> Instruction {
+    build(accounts, ix::InitializeProgramConfig)
+}
+
+/// Propose a new program config admin, who has to accept the role. None cancels the proposal.
+pub fn propose_admin(accounts: accounts::ProposeAdmin, new_admin: Option<Pubkey>) -> Instruction {
+    build(accounts, ix::ProposeAdmin { new_admin })
+}
+
+/// Accept the admin role proposed by the current admin.
+pub fn accept_admin(accounts: accounts::AcceptAdmin) -> Instruction {
+    build(accounts, ix::AcceptAdmin)
+}
+
+/// Pause or resume the state changing instructions of this program.
+pub fn set_paused(accounts: accounts::SetPaused, paused: bool) -> Instruction {
+    build(accounts, ix::SetPaused { paused })
+}
+
+/// Create a mint with a close authority, so it can be closed once its supply is zero.
+pub fn mint_close_authority(accounts: accounts::MintCloseAuthority, data_len: u64) -> Instruction {
+    build(accounts, ix::MintCloseAuthority { data_len })
+}
+
+/// Close a mint through its close authority.
+pub fn close_mint_account(accounts: accounts::CloseMintAccount) -> Instruction {
+    build(accounts, ix::CloseMintAccount)
+}
+
+/// Close a mint after checking the close authority and zero supply, withdrawing withheld fees first.
+pub fn safe_close_mint(accounts: accounts::SafeCloseMint) -> Instruction {
+    build(accounts, ix::SafeCloseMint)
+}
+
+/// Harvest withheld fees and close the empty token accounts passed as remaining accounts, reporting the skipped ones.
+///
+/// `remaining_accounts` are the writable token accounts to close, at most 30.
+pub fn close_token_accounts(
+    accounts: accounts::CloseTokenAccounts,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(accounts, ix::CloseTokenAccounts, remaining_accounts)
+}
+
+/// Create a mint with a transfer fee config and its fee and withdraw authorities.
+pub fn transfer_fee_config(
+    accounts: accounts::TransferFeeConfig,
+    data_len: u64,
+    transfer_fee_config_authority: Option<Pubkey>,
+    withdraw_withheld_authority: Option<Pubkey>,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Instruction {
+    build(
+        accounts,
+        ix::TransferFeeConfig {
+            data_len,
+            transfer_fee_config_authority,
+            withdraw_withheld_authority,
+            transfer_fee_basis_points,
+            maximum_fee,
+        },
+    )
+}
+
+/// Mint tokens to an associated token account.
+pub fn mint_to(accounts: accounts::MintTo, amount: u64) -> Instruction {
+    build(accounts, ix::MintTo { amount })
+}
+
+/// Transfer tokens between accounts of a transfer fee mint, stating the expected fee.
+pub fn transfer_to(accounts: accounts::TransferTo, amount: u64, fee: u64) -> Instruction {
+    build(accounts, ix::TransferTo { amount, fee })
+}
+
+/// Withdraw the fees withheld in token accounts to the authority's associated token account.
+///
+/// `remaining_accounts` are the writable token accounts holding withheld fees.
+pub fn withdraw_withheld_account(
+    accounts: accounts::WithdrawWithheldAccount,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(accounts, ix::WithdrawWithheldAccount, remaining_accounts)
+}
+
+/// Harvest the fees withheld in token accounts to the mint.
+///
+/// `remaining_accounts` are the writable token accounts holding withheld fees.
+pub fn harvest_withheld_token(
+    accounts: accounts::HarvestWithheldToken,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(accounts, ix::HarvestWithheldToken, remaining_accounts)
+}
+
+/// Withdraw the fees withheld in the mint to the authority's associated token account.
+pub fn withdraw_withheld_mint(accounts: accounts::WithdrawWithheldMint) -> Instruction {
+    build(accounts, ix::WithdrawWithheldMint)
+}
+
+/// Create a mint whose new accounts start in the given default state.
+pub fn default_account_state(
+    accounts: accounts::DefaultAccountState,
+    data_len: u64,
+    state: DefaultState,
+) -> Instruction {
+    build(accounts, ix::DefaultAccountState { data_len, state })
+}
+
+/// Update the mint default state, signed by the freeze authority or its multisig signers.
+///
+/// `remaining_accounts` are the multisig signers when the freeze authority is a multisig.
+pub fn update_default_account_state(
+    accounts: accounts::UpdateDefaultAccountState,
+    account_state: DefaultState,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts,
+        ix::UpdateDefaultAccountState { account_state },
+        remaining_accounts,
+    )
+}
+
+/// Rotate or remove any mint authority, signed by the current authority or its multisig signers.
+///
+/// `remaining_accounts` are the multisig signers when the current authority is a multisig.
+pub fn set_authority(
+    accounts: accounts::SetAuthority,
+    kind: AuthorityKind,
+    new_authority: Option<Pubkey>,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts,
+        ix::SetAuthority {
+            kind,
+            new_authority,
+        },
+        remaining_accounts,
+    )
+}
+
+/// Hand the freeze authority of a default frozen mint to the program for KYC onboarding.
+pub fn initialize_kyc(accounts: accounts::InitializeKyc) -> Instruction {
+    build(accounts, ix::InitializeKyc)
+}
+
+/// Create an access request for a frozen token account owned by the wallet.
+pub fn request_access(accounts: accounts::RequestAccess) -> Instruction {
+    build(accounts, ix::RequestAccess)
+}
+
+/// Compliance officer approves the access request and the token account gets thawed.
+pub fn approve_access(accounts: accounts::ApproveAccess) -> Instruction {
+    build(accounts, ix::ApproveAccess)
+}
+
+/// Compliance officer revokes an approved access and the token account gets frozen again.
+pub fn revoke_access(accounts: accounts::RevokeAccess) -> Instruction {
+    build(accounts, ix::RevokeAccess)
+}
+
+/// Freeze a batch of token accounts and record the reason code for each of them.
+///
+/// `remaining_accounts` are pairs of writable token account and freeze record address.
+pub fn freeze_accounts(
+    accounts: accounts::FreezeAccounts,
+    reason_code: u16,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts,
+        ix::FreezeAccounts { reason_code },
+        remaining_accounts,
+    )
+}
+
+/// Thaw a batch of token accounts and record the reason code for each of them.
+///
+/// `remaining_accounts` are pairs of writable token account and freeze record address.
+pub fn thaw_accounts(
+    accounts: accounts::ThawAccounts,
+    reason_code: u16,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts,
+        ix::ThawAccounts { reason_code },
+        remaining_accounts,
+    )
+}
+
+/// Create a mint and a token account whose owner can't be changed.
+pub fn immutable_owner(
+    accounts: accounts::ImmutableOwner,
+    mint_len: u64,
+    account_len: u64,
+) -> Instruction {
+    build(
+        accounts,
+        ix::ImmutableOwner {
+            mint_len,
+            account_len,
+        },
+    )
+}
+
+/// Create a non-transferable mint and an immutable owner token account for it.
+pub fn non_transferable_token(
+    accounts: accounts::NonTransferableToken,
+    mint_len: u64,
+    account_len: u64,
+) -> Instruction {
+    build(
+        accounts,
+        ix::NonTransferableToken {
+            mint_len,
+            account_len,
+        },
+    )
+}
+
+/// Create a soulbound credential mint: non-transferable, issuer as permanent delegate, with metadata.
+pub fn create_credential_mint(
+    accounts: accounts::CreateCredentialMint,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Instruction {
+    build(accounts, ix::CreateCredentialMint { name, symbol, uri })
+}
+
+/// Issue the credential to a holder, writing expiry and claims into the mint metadata.
+pub fn issue_credential(
+    accounts: accounts::IssueCredential,
+    expires_at: i64,
+    claims: Vec<CredentialClaim>,
+) -> Instruction {
+    build(accounts, ix::IssueCredential { expires_at, claims })
+}
+
+/// Revoke a credential by burning it through the issuer's permanent delegate. The mint records
+/// the revocation and never issues again.
+pub fn revoke_credential(accounts: accounts::RevokeCredential) -> Instruction {
+    build(accounts, ix::RevokeCredential)
+}
+
+/// Report whether the holder account still holds an unrevoked, unexpired credential. Meant to be simulated.
+pub fn verify_credential(accounts: accounts::VerifyCredential) -> Instruction {
+    build(accounts, ix::VerifyCredential)
+}
+
+/// Create a token account that requires a memo on incoming transfers.
+pub fn enable_memo(accounts: accounts::EnableMemo, account_len: u64) -> Instruction {
+    build(accounts, ix::EnableMemo { account_len })
+}
+
+/// Stop requiring memos on incoming transfers.
+pub fn disable_memo(accounts: accounts::DisableMemo) -> Instruction {
+    build(accounts, ix::DisableMemo)
+}
+
+/// Report whether a token account requires incoming memos and has CPI Guard enabled. Meant to be simulated.
+pub fn transfer_requirements(accounts: accounts::TransferRequirements) -> Instruction {
+    build(accounts, ix::TransferRequirements)
+}
+
+/// Transfer tokens with a caller supplied memo, either free text or a validated payment reference.
+pub fn memo_transfer(
+    accounts: accounts::MemoTransfer,
+    amount: u64,
+    decimals: u8,
+    memo: TransferMemo,
+) -> Instruction {
+    build(
+        accounts,
+        ix::MemoTransfer {
+            amount,
+            decimals,
+            memo,
+        },
+    )
+}
+
+/// Reallocate a token account to add a single extension.
+pub fn realloc(accounts: accounts::Realloc) -> Instruction {
+    build(accounts, ix::Realloc)
+}
+
+/// Reallocate a token account for several extensions at once and enable them, charging only the rent difference.
+///
+/// With [`AccountExtension::CpiGuard`] the Token-2022 enable instruction follows the program instruction.
+pub fn realloc_account(
+    accounts: accounts::ReallocAccount,
+    extensions: Vec<AccountExtension>,
+) -> Vec<Instruction> {
+    let cpi_guard = extensions.contains(&AccountExtension::CpiGuard);
+    let ix = build(accounts, ix::ReallocAccount { extensions });
+
+    if cpi_guard {
+        with_cpi_guard_toggle(ix, &accounts.token_account, &accounts.owner, true)
+    } else {
+        vec![ix]
+    }
+}
+
+/// Create an interest bearing mint with the given rate.
+pub fn interest_bearing_token(
+    accounts: accounts::InterestBearingToken,
+    mint_len: u64,
+    rate: i16,
+) -> Instruction {
+    build(accounts, ix::InterestBearingToken { mint_len, rate })
+}
+
+/// Convert a raw amount into a UI amount with the accrued interest. Meant to be simulated.
+pub fn amount_to_ui_amount(accounts: accounts::AmountToUiAmount, amount: u64) -> Instruction {
+    build(accounts, ix::AmountToUiAmount { amount })
+}
+
+/// Update the interest rate of an interest bearing mint, signed by the rate authority.
+pub fn update_rate(accounts: accounts::UpdateRate, rate: i16) -> Instruction {
+    build(accounts, ix::UpdateRate { rate })
+}
+
+/// Convert a UI amount with the accrued interest back into a raw amount. Meant to be simulated.
+pub fn ui_amount_to_amount(accounts: accounts::UiAmountToAmount, ui_amount: String) -> Instruction {
+    build(accounts, ix::UiAmountToAmount { ui_amount })
+}
+
+/// Project an amount with the interest accrued up to now or a future timestamp. Meant to be simulated.
+pub fn project_interest(
+    accounts: accounts::ProjectInterest,
+    amount: u64,
+    unix_timestamp: Option<i64>,
+) -> Instruction {
+    build(
+        accounts,
+        ix::ProjectInterest {
+            amount,
+            unix_timestamp,
+        },
+    )
+}
+
+/// Move the interest rate authority to a governance PDA with rate bounds and a timelock.
+pub fn initialize_rate_governance(
+    accounts: accounts::InitializeRateGovernance,
+    min_rate: i16,
+    max_rate: i16,
+    max_change_per_period: u16,
+    period: i64,
+    timelock: i64,
+) -> Instruction {
+    build(
+        accounts,
+        ix::InitializeRateGovernance {
+            min_rate,
+            max_rate,
+            max_change_per_period,
+            period,
+            timelock,
+        },
+    )
+}
+
+/// Propose a new interest rate within the governance bounds.
+pub fn propose_rate(accounts: accounts::ProposeRate, rate: i16) -> Instruction {
+    build(accounts, ix::ProposeRate { rate })
+}
+
+/// Withdraw the pending interest rate before it is executed.
+pub fn cancel_rate(accounts: accounts::ProposeRate) -> Instruction {
+    build(accounts, ix::CancelRate)
+}
+
+/// Apply the pending interest rate once the timelock has elapsed.
+pub fn execute_rate(accounts: accounts::ExecuteRate) -> Instruction {
+    build(accounts, ix::ExecuteRate)
+}
+
+/// Create a mint with a permanent delegate.
+pub fn permanent_delegate(accounts: accounts::PermanentDelegate, mint_len: u64) -> Instruction {
+    build(accounts, ix::PermanentDelegate { mint_len })
+}
+
+/// Create the associated token account of a permanent delegate mint.
+pub fn create_ata(accounts: accounts::CreateAta) -> Instruction {
+    build(accounts, ix::CreateAta)
+}
+
+/// Burn tokens from any holder through the permanent delegate.
+pub fn burn_cpi(accounts: accounts::BurnCpi, amount: u64) -> Instruction {
+    build(accounts, ix::BurnCpi { amount })
+}
+
+/// Move tokens from any holder to a treasury using the permanent delegate, with a reason memo.
+pub fn clawback(accounts: accounts::Clawback, amount: u64, reason: String) -> Instruction {
+    build(accounts, ix::Clawback { amount, reason })
+}
+
+/// Move the permanent delegate to a governance PDA controlled by an M-of-N signer set.
+pub fn initialize_delegate_governance(
+    accounts: accounts::InitializeDelegateGovernance,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Instruction {
+    build(
+        accounts,
+        ix::InitializeDelegateGovernance { signers, threshold },
+    )
+}
+
+/// Propose a burn or clawback through the governed permanent delegate, expiring after ttl seconds.
+pub fn propose_delegate_action(
+    accounts: accounts::ProposeDelegateAction,
+    action: DelegateAction,
+    amount: u64,
+    reason: String,
+    ttl: i64,
+) -> Instruction {
+    build(
+        accounts,
+        ix::ProposeDelegateAction {
+            action,
+            amount,
+            reason,
+            ttl,
+        },
+    )
+}
+
+/// Approve an active delegate proposal.
+pub fn approve_delegate_proposal(accounts: accounts::ApproveDelegateProposal) -> Instruction {
+    build(accounts, ix::ApproveDelegateProposal)
+}
+
+/// Cancel an active delegate proposal, only its proposer can.
+pub fn cancel_delegate_proposal(accounts: accounts::CancelDelegateProposal) -> Instruction {
+    build(accounts, ix::CancelDelegateProposal)
+}
+
+/// Execute a delegate proposal once it reached the approval threshold.
+pub fn execute_delegate_proposal(accounts: accounts::ExecuteDelegateProposal) -> Instruction {
+    build(accounts, ix::ExecuteDelegateProposal)
+}
+
+/// Create and initialize a token account of the given length, CPI Guard is turned on with enable_cpi_guard.
+pub fn initialize_token_account_with(
+    accounts: accounts::InitializeTokenAccountWith,
+    account_len: u64,
+) -> Instruction {
+    build(accounts, ix::InitializeTokenAccountWith { account_len })
+}
+
+/// Create a token account for an existing mint with any mix of account extensions and an optional delegate.
+///
+/// With `options.cpi_guard` the Token-2022 enable instruction follows the program instruction.
+pub fn create_token_account(
+    accounts: accounts::CreateTokenAccount,
+    options: TokenAccountOptions,
+) -> Vec<Instruction> {
+    let ix = build(accounts, ix::CreateTokenAccount { options });
+
+    if options.cpi_guard {
+        with_cpi_guard_toggle(ix, &accounts.token_account, &accounts.owner, true)
+    } else {
+        vec![ix]
+    }
+}
+
+/// Transfer tokens through the program, which fails when the source has CPI Guard enabled.
+pub fn transfer_token(accounts: accounts::TransferToken, amount: u64, decimals: u8) -> Instruction {
+    build(accounts, ix::TransferToken { amount, decimals })
+}
+
+/// Make room for CPI Guard, followed by the Token-2022 enable instruction.
+pub fn enable_cpi_guard(accounts: accounts::EnableCpiGuard) -> Vec<Instruction> {
+    with_cpi_guard_toggle(
+        build(accounts, ix::EnableCpiGuard),
+        &accounts.token_account,
+        &accounts.owner,
+        true,
+    )
+}
+
+/// Check the CPI Guard extension, followed by the Token-2022 disable instruction.
+pub fn disable_cpi_guard(accounts: accounts::DisableCpiGuard) -> Vec<Instruction> {
+    with_cpi_guard_toggle(
+        build(accounts, ix::DisableCpiGuard),
+        &accounts.token_account,
+        &accounts.owner,
+        false,
+    )
+}
+
+/// Create a mint with a transfer hook program.
+pub fn initialize_hook_mint(
+    accounts: accounts::InitializeHookMint,
+    mint_len: u64,
+    transfer_hook_program_id: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts,
+        ix::InitializeHookMint {
+            mint_len,
+            transfer_hook_program_id,
+        },
+    )
+}
+
+/// Transfer tokens of a transfer hook mint.
+///
+/// `remaining_accounts` are the extra accounts the transfer hook program requires.
+pub fn transfer_hook_token(
+    accounts: accounts::TransferHookToken,
+    amount: u64,
+    decimals: u8,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts,
+        ix::TransferHookToken { amount, decimals },
+        remaining_accounts,
+    )
+}
+
+/// Create a mint with its metadata (name, symbol, uri) stored in the mint itself.
+pub fn initialize_metadata_pointer(
+    accounts: accounts::InitializeMetadataPointer,
+    mint_len: u64,
+    decimals: u8,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Instruction {
+    build(
+        accounts,
+        ix::InitializeMetadataPointer {
+            mint_len,
+            decimals,
+            name,
+            symbol,
+            uri,
+        },
+    )
+}
+
+/// Update a metadata field of a mint.
+pub fn update_metadata_field(
+    accounts: accounts::UpdateMetadataField,
+    field: Field,
+    value: String,
+) -> Instruction {
+    build(accounts, ix::UpdateMetadataField { field, value })
+}
+
+/// Initialize confidential mint with an approval policy and an optional auditor ElGamal pubkey.
+pub fn initialize_confidential_mint(
+    accounts: accounts::InitializeConfidentialMint,
+    mint_len: u64,
+    decimals: u8,
+    auto_approve_new_accounts: bool,
+    auditor_elgamal_pubkey: Option<[u8; 32]>,
+) -> Instruction {
+    build(
+        accounts,
+        ix::InitializeConfidentialMint {
+            mint_len,
+            decimals,
+            auto_approve_new_accounts,
+            auditor_elgamal_pubkey,
+        },
+    )
+}
+
+/// Create a token account for a confidential mint.
+pub fn initialize_confidential_account(
+    accounts: accounts::InitializeConfidentialAccount,
+    account_len: u64,
+) -> Instruction {
+    build(accounts, ix::InitializeConfidentialAccount { account_len })
+}
+
+/// Approve a configured confidential account when the mint requires manual approval.
+pub fn approve_confidential_account(accounts: accounts::ApproveConfidentialAccount) -> Instruction {
+    build(accounts, ix::ApproveConfidentialAccount)
+}
+
+/// Update the approval policy and auditor ElGamal pubkey of a confidential mint.
+pub fn update_confidential_mint(
+    accounts: accounts::UpdateConfidentialMint,
+    auto_approve_new_accounts: bool,
+    auditor_elgamal_pubkey: Option<[u8; 32]>,
+) -> Instruction {
+    build(
+        accounts,
+        ix::UpdateConfidentialMint {
+            auto_approve_new_accounts,
+            auditor_elgamal_pubkey,
+        },
+    )
+}
+
+/// Initialize a mint with transfer fee, confidential transfer and confidential transfer fee config.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_confidential_transfer_fee_mint(
+    accounts: accounts::InitializeConfidentialTransferFeeMint,
+    mint_len: u64,
+    decimals: u8,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+    auto_approve_new_accounts: bool,
+    auditor_elgamal_pubkey: Option<[u8; 32]>,
+    withdraw_withheld_authority_elgamal_pubkey: [u8; 32],
+) -> Instruction {
+    build(
+        accounts,
+        ix::InitializeConfidentialTransferFeeMint {
+            mint_len,
+            decimals,
+            transfer_fee_basis_points,
+            maximum_fee,
+            auto_approve_new_accounts,
+            auditor_elgamal_pubkey,
+            withdraw_withheld_authority_elgamal_pubkey,
+        },
+    )
+}
+
+/// Harvest encrypted withheld tokens from accounts to the mint.
+///
+/// `remaining_accounts` are the writable token accounts holding withheld fees.
+pub fn harvest_confidential_withheld_token(
+    accounts: accounts::HarvestConfidentialWithheldToken,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts,
+        ix::HarvestConfidentialWithheldToken,
+        remaining_accounts,
+    )
+}
+
+/// Withdraw encrypted withheld tokens from mint using a pre-verified equality proof.
+pub fn withdraw_confidential_withheld_mint(
+    accounts: accounts::WithdrawConfidentialWithheldMint,
+    new_decryptable_available_balance: [u8; 36],
+) -> Instruction {
+    build(
+        accounts,
+        ix::WithdrawConfidentialWithheldMint {
+            new_decryptable_available_balance,
+        },
+    )
+}
+
+/// Withdraw encrypted withheld tokens from accounts using a pre-verified equality proof.
+///
+/// `remaining_accounts` are the writable token accounts holding withheld fees.
+pub fn withdraw_confidential_withheld_account(
+    accounts: accounts::WithdrawConfidentialWithheldAccount,
+    new_decryptable_available_balance: [u8; 36],
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts,
+        ix::WithdrawConfidentialWithheldAccount {
+            new_decryptable_available_balance,
+        },
+        remaining_accounts,
+    )
+}
+
+/// Create a mint with a group pointer to itself.
+pub fn initialize_group_pointer(
+    accounts: accounts::InitializeGroupPointer,
+    mint_len: u64,
+    decimals: u8,
+    max_size: u32,
+) -> Instruction {
+    build(
+        accounts,
+        ix::InitializeGroupPointer {
+            mint_len,
+            decimals,
+            max_size,
+        },
+    )
+}
+
+/// Create a mint with a group member pointer to itself, for the given group mint.
+pub fn initialize_member_pointer(
+    accounts: accounts::InitializeMemberPointer,
+    mint_len: u64,
+    decimals: u8,
+) -> Instruction {
+    build(accounts, ix::InitializeMemberPointer { mint_len, decimals })
+}
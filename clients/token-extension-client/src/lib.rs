@@ -0,0 +1,21 @@
+//! Rust client for the token-extension program: instruction builders, PDA helpers,
+//! decoders for Token-2022 accounts with extensions and a transaction builder that adds
+//! compute budget instructions.
+//!
+//! The accounts structs, instruction data, types and program owned accounts are generated
+//! at build time from `idl/token_extension.json`, so the client does not depend on the
+//! program crate. Copy `target/idl/token_extension.json` there after `anchor build` when
+//! the program interface changes.
+
+pub mod instruction;
+pub mod pda;
+pub mod state;
+pub mod transaction;
+
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/token_extension.rs"));
+}
+
+pub use generated::{accounts, constants::*, types::*};
+
+anchor_lang::declare_id!("2A4udxjXYzZnJgX65cbVpr3421bpWrrjUBNey1iWrEiy");
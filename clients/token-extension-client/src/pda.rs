@@ -0,0 +1,127 @@
+//! Addresses of the accounts the program derives from its seeds.
+
+use {
+    crate::{
+        ACCESS_REQUEST_SEED, DELEGATE_GOVERNANCE_SEED, DELEGATE_PROPOSAL_SEED, FREEZE_RECORD_SEED,
+        KYC_CONFIG_SEED, PROGRAM_CONFIG_SEED, RATE_GOVERNANCE_SEED,
+    },
+    anchor_lang::prelude::Pubkey,
+    anchor_spl::associated_token::get_associated_token_address_with_program_id,
+};
+
+pub fn find_program_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROGRAM_CONFIG_SEED], &crate::ID)
+}
+
+pub fn find_rate_governance_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RATE_GOVERNANCE_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_kyc_config_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[KYC_CONFIG_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_access_request_address(token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ACCESS_REQUEST_SEED, token_account.as_ref()], &crate::ID)
+}
+
+pub fn find_freeze_record_address(token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FREEZE_RECORD_SEED, token_account.as_ref()], &crate::ID)
+}
+
+pub fn find_delegate_governance_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DELEGATE_GOVERNANCE_SEED, mint.as_ref()], &crate::ID)
+}
+
+// `id` is the governance proposal count at the time the proposal is created.
+pub fn find_delegate_proposal_address(delegate_governance: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            DELEGATE_PROPOSAL_SEED,
+            delegate_governance.as_ref(),
+            &id.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+// Associated token account of `wallet` for a Token-2022 `mint`.
+pub fn get_associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(wallet, mint, &spl_token_2022::ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::str::FromStr};
+
+    #[test]
+    fn program_config_address_is_pinned() {
+        assert_eq!(
+            find_program_config_address(),
+            (
+                Pubkey::from_str("4U6SC8tXaq2bMNppLWwtKziL4SJi5eazmCdFShhdNnW8").unwrap(),
+                252
+            )
+        );
+    }
+
+    #[test]
+    fn addresses_derive_from_the_program_seeds() {
+        let mint = Pubkey::new_unique();
+        let token_account = Pubkey::new_unique();
+        let find = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &crate::ID);
+
+        assert_eq!(
+            find_rate_governance_address(&mint),
+            find(&[b"rate-governance", mint.as_ref()])
+        );
+        assert_eq!(
+            find_kyc_config_address(&mint),
+            find(&[b"kyc-config", mint.as_ref()])
+        );
+        assert_eq!(
+            find_access_request_address(&token_account),
+            find(&[b"access-request", token_account.as_ref()])
+        );
+        assert_eq!(
+            find_freeze_record_address(&token_account),
+            find(&[b"freeze-record", token_account.as_ref()])
+        );
+        assert_eq!(
+            find_delegate_governance_address(&mint),
+            find(&[b"delegate-governance", mint.as_ref()])
+        );
+    }
+
+    #[test]
+    fn delegate_proposal_address_takes_the_little_endian_id() {
+        let delegate_governance = Pubkey::new_from_array([7; 32]);
+
+        assert_eq!(
+            find_delegate_proposal_address(&delegate_governance, 3),
+            (
+                Pubkey::from_str("6BqcMiErUs6bW3gqxXjva4UXr6mENJtqpjxhREEiRMeY").unwrap(),
+                255
+            )
+        );
+        assert_ne!(
+            find_delegate_proposal_address(&delegate_governance, 3),
+            find_delegate_proposal_address(&delegate_governance, 4)
+        );
+    }
+
+    #[test]
+    fn associated_token_address_uses_token_2022() {
+        let wallet = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        assert_eq!(
+            get_associated_token_address(&wallet, &mint),
+            Pubkey::find_program_address(
+                &[wallet.as_ref(), spl_token_2022::ID.as_ref(), mint.as_ref()],
+                &anchor_spl::associated_token::ID,
+            )
+            .0
+        );
+    }
+}
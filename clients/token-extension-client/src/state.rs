@@ -0,0 +1,195 @@
+//! Decoders for Token-2022 mints and token accounts with their extensions, and for the
+//! accounts owned by the program.
+
+pub use crate::{
+    generated::state::{
+        AccessRequest, DelegateGovernance, DelegateProposal, FreezeRecord, KycConfig,
+        ProgramConfig, RateGovernance,
+    },
+    AccessStatus, ProposalStatus,
+};
+use {
+    anchor_lang::{prelude::*, AccountDeserialize},
+    spl_token_2022::{
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensionsOwned},
+        state::{Account, Mint},
+    },
+    spl_token_metadata_interface::state::TokenMetadata,
+};
+
+pub type MintWithExtensions = StateWithExtensionsOwned<Mint>;
+
+pub type TokenAccountWithExtensions = StateWithExtensionsOwned<Account>;
+
+pub fn unpack_mint(data: &[u8]) -> Result<MintWithExtensions> {
+    Ok(MintWithExtensions::unpack(data.to_vec())?)
+}
+
+pub fn unpack_token_account(data: &[u8]) -> Result<TokenAccountWithExtensions> {
+    Ok(TokenAccountWithExtensions::unpack(data.to_vec())?)
+}
+
+// Metadata stored in the mint itself, None when the mint has no TokenMetadata extension.
+pub fn token_metadata(mint: &MintWithExtensions) -> Result<Option<TokenMetadata>> {
+    if !mint
+        .get_extension_types()?
+        .contains(&ExtensionType::TokenMetadata)
+    {
+        return Ok(None);
+    }
+
+    Ok(Some(mint.get_variable_len_extension::<TokenMetadata>()?))
+}
+
+// Decode an account owned by the program, checking its discriminator.
+pub fn decode_account<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        anchor_lang::Discriminator,
+        spl_token_2022::{
+            extension::{
+                metadata_pointer::MetadataPointer, transfer_fee::TransferFeeConfig,
+                StateWithExtensionsMut,
+            },
+            state::AccountState,
+        },
+    };
+
+    fn mint_bytes(extensions: &[ExtensionType]) -> (Vec<u8>, Pubkey) {
+        let mint_authority = Pubkey::new_unique();
+        let mut data =
+            vec![0; ExtensionType::try_calculate_account_len::<Mint>(extensions).unwrap()];
+
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        state.base = Mint {
+            mint_authority: Some(mint_authority).into(),
+            supply: 1_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: None.into(),
+        };
+        state.pack_base();
+        for extension in extensions {
+            match extension {
+                ExtensionType::TransferFeeConfig => {
+                    state.init_extension::<TransferFeeConfig>(true).unwrap();
+                }
+                ExtensionType::MetadataPointer => {
+                    state.init_extension::<MetadataPointer>(true).unwrap();
+                }
+                extension => panic!("no test setup for {extension:?}"),
+            }
+        }
+        state.init_account_type().unwrap();
+
+        (data, mint_authority)
+    }
+
+    #[test]
+    fn unpack_mint_reads_base_and_extensions() {
+        let (data, mint_authority) = mint_bytes(&[
+            ExtensionType::TransferFeeConfig,
+            ExtensionType::MetadataPointer,
+        ]);
+
+        let mint = unpack_mint(&data).unwrap();
+
+        assert_eq!(mint.base.mint_authority, Some(mint_authority).into());
+        assert_eq!(mint.base.supply, 1_000);
+        assert_eq!(mint.base.decimals, 6);
+        assert_eq!(
+            mint.get_extension_types().unwrap(),
+            [
+                ExtensionType::TransferFeeConfig,
+                ExtensionType::MetadataPointer
+            ]
+        );
+        assert_eq!(token_metadata(&mint).unwrap(), None);
+    }
+
+    #[test]
+    fn token_metadata_reads_the_mint_metadata() {
+        let (mut data, _) = mint_bytes(&[ExtensionType::MetadataPointer]);
+        let metadata = TokenMetadata {
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            uri: "https://example.com/token.json".to_string(),
+            additional_metadata: vec![("holder".to_string(), "alice".to_string())],
+            ..Default::default()
+        };
+        data.resize(data.len() + metadata.tlv_size_of().unwrap(), 0);
+        StateWithExtensionsMut::<Mint>::unpack(&mut data)
+            .unwrap()
+            .init_variable_len_extension(&metadata, false)
+            .unwrap();
+
+        let mint = unpack_mint(&data).unwrap();
+
+        assert_eq!(token_metadata(&mint).unwrap(), Some(metadata));
+    }
+
+    #[test]
+    fn unpack_token_account_reads_base() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut data = vec![0; ExtensionType::try_calculate_account_len::<Account>(&[]).unwrap()];
+        let mut state = StateWithExtensionsMut::<Account>::unpack_uninitialized(&mut data).unwrap();
+        state.base = Account {
+            mint,
+            owner,
+            amount: 42,
+            state: AccountState::Frozen,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+
+        let account = unpack_token_account(&data).unwrap();
+
+        assert_eq!(account.base.mint, mint);
+        assert_eq!(account.base.owner, owner);
+        assert_eq!(account.base.amount, 42);
+        assert!(account.base.is_frozen());
+    }
+
+    #[test]
+    fn decode_account_reads_program_config() {
+        let admin = Pubkey::new_unique();
+        let pending_admin = Pubkey::new_unique();
+        let data = [
+            ProgramConfig::DISCRIMINATOR.as_slice(),
+            admin.as_ref(),
+            &[1],
+            pending_admin.as_ref(),
+            &[1, 254],
+        ]
+        .concat();
+
+        assert_eq!(
+            decode_account::<ProgramConfig>(&data).unwrap(),
+            ProgramConfig {
+                admin,
+                pending_admin: Some(pending_admin),
+                paused: true,
+                bump: 254,
+            }
+        );
+    }
+
+    #[test]
+    fn decode_account_rejects_another_discriminator() {
+        let data = [
+            FreezeRecord::DISCRIMINATOR.as_slice(),
+            Pubkey::new_unique().as_ref(),
+            &[0, 0],
+        ]
+        .concat();
+
+        assert!(decode_account::<ProgramConfig>(&data).is_err());
+    }
+}
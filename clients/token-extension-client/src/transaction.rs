@@ -0,0 +1,143 @@
+//! Transactions that put compute budget instructions in front of the program instructions.
+
+use {
+    anchor_lang::solana_program::instruction::Instruction,
+    solana_sdk::{
+        compute_budget::ComputeBudgetInstruction, hash::Hash, message::Message, pubkey::Pubkey,
+        signer::SignerError, signers::Signers, transaction::Transaction,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct TransactionBuilder {
+    payer: Pubkey,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
+    instructions: Vec<Instruction>,
+}
+
+impl TransactionBuilder {
+    pub fn new(payer: Pubkey) -> Self {
+        Self {
+            payer,
+            compute_unit_limit: None,
+            compute_unit_price: None,
+            instructions: Vec::new(),
+        }
+    }
+
+    /// Maximum number of compute units the transaction may consume.
+    pub fn compute_unit_limit(mut self, units: u32) -> Self {
+        self.compute_unit_limit = Some(units);
+        self
+    }
+
+    /// Priority fee in micro-lamports per compute unit.
+    pub fn compute_unit_price(mut self, micro_lamports: u64) -> Self {
+        self.compute_unit_price = Some(micro_lamports);
+        self
+    }
+
+    pub fn instruction(mut self, instruction: Instruction) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    pub fn instructions(mut self, instructions: impl IntoIterator<Item = Instruction>) -> Self {
+        self.instructions.extend(instructions);
+        self
+    }
+
+    /// Compute budget instructions first, then the added instructions in order.
+    pub fn into_instructions(self) -> Vec<Instruction> {
+        let budget = [
+            self.compute_unit_limit
+                .map(ComputeBudgetInstruction::set_compute_unit_limit),
+            self.compute_unit_price
+                .map(ComputeBudgetInstruction::set_compute_unit_price),
+        ];
+
+        budget
+            .into_iter()
+            .flatten()
+            .chain(self.instructions)
+            .collect()
+    }
+
+    pub fn message(self) -> Message {
+        let payer = self.payer;
+        Message::new(&self.into_instructions(), Some(&payer))
+    }
+
+    pub fn build<T: Signers + ?Sized>(
+        self,
+        signers: &T,
+        recent_blockhash: Hash,
+    ) -> std::result::Result<Transaction, SignerError> {
+        let mut transaction = Transaction::new_unsigned(self.message());
+        transaction.try_sign(signers, recent_blockhash)?;
+        Ok(transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_sdk::{compute_budget, signature::Keypair, signer::Signer},
+    };
+
+    fn program_instruction(data: u8) -> Instruction {
+        Instruction::new_with_bytes(crate::ID, &[data], vec![])
+    }
+
+    #[test]
+    fn compute_budget_instructions_come_first() {
+        let instructions = TransactionBuilder::new(Pubkey::new_unique())
+            .instruction(program_instruction(1))
+            .compute_unit_price(1_000)
+            .instructions([program_instruction(2), program_instruction(3)])
+            .compute_unit_limit(200_000)
+            .into_instructions();
+
+        assert_eq!(
+            instructions,
+            [
+                ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+                ComputeBudgetInstruction::set_compute_unit_price(1_000),
+                program_instruction(1),
+                program_instruction(2),
+                program_instruction(3),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_compute_budget_instructions_unless_set() {
+        let instructions = TransactionBuilder::new(Pubkey::new_unique())
+            .instruction(program_instruction(1))
+            .into_instructions();
+
+        assert_eq!(instructions, [program_instruction(1)]);
+        assert!(instructions
+            .iter()
+            .all(|ix| ix.program_id != compute_budget::ID));
+    }
+
+    #[test]
+    fn build_signs_with_the_payer_first() {
+        let payer = Keypair::new();
+        let blockhash = Hash::new_unique();
+
+        let transaction = TransactionBuilder::new(payer.pubkey())
+            .compute_unit_price(1)
+            .instruction(program_instruction(1))
+            .build(&[&payer], blockhash)
+            .unwrap();
+
+        assert_eq!(transaction.message.account_keys[0], payer.pubkey());
+        assert_eq!(transaction.message.recent_blockhash, blockhash);
+        assert!(transaction.is_signed());
+        transaction.verify().unwrap();
+    }
+}
@@ -1,7 +1,16 @@
 use anchor_lang::{prelude::*, solana_program::system_instruction::transfer};
+use anchor_spl::{
+    token_2022::{
+        approve, burn, close_account, set_authority, spl_token_2022::instruction::AuthorityType,
+        transfer_checked, Approve, Burn, CloseAccount, SetAuthority, TransferChecked,
+    },
+    token_interface::{Mint, Token2022, TokenAccount},
+};
 
 declare_id!("GFZBH5E5peCPF6KZPzXQpx4YiWmqSwMZHsmG1yF9fvai");
 
+// Every token instruction below is issued from inside this program, so Token-2022
+// sees it as a CPI and applies the CPI Guard rules of the account.
 #[program]
 pub mod opaque {
 
@@ -23,6 +32,98 @@ pub mod opaque {
         )?;
         Ok(())
     }
+
+    // CPI Guard blocks approving a delegate.
+    pub fn approve_delegate(ctx: Context<ApproveDelegate>, amount: u64) -> Result<()> {
+        let all = ctx.accounts;
+
+        approve(
+            CpiContext::new(
+                all.token_2022_program.to_account_info(),
+                Approve {
+                    to: all.token_account.to_account_info(),
+                    delegate: all.delegate.to_account_info(),
+                    authority: all.owner.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+
+    // CPI Guard blocks changing the account owner.
+    pub fn set_owner(ctx: Context<SetTokenAuthority>, new_authority: Pubkey) -> Result<()> {
+        let all = ctx.accounts;
+
+        set_authority(
+            all.set_authority_cpi(),
+            AuthorityType::AccountOwner,
+            Some(new_authority),
+        )
+    }
+
+    // CPI Guard blocks setting a close authority, removing it is allowed.
+    pub fn set_close_authority(
+        ctx: Context<SetTokenAuthority>,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        let all = ctx.accounts;
+
+        set_authority(
+            all.set_authority_cpi(),
+            AuthorityType::CloseAccount,
+            new_authority,
+        )
+    }
+
+    // CPI Guard blocks closing unless lamports go back to the owner.
+    pub fn close_token_account(ctx: Context<CloseTokenAccount>) -> Result<()> {
+        let all = ctx.accounts;
+
+        close_account(CpiContext::new(
+            all.token_2022_program.to_account_info(),
+            CloseAccount {
+                account: all.token_account.to_account_info(),
+                destination: all.destination.to_account_info(),
+                authority: all.owner.to_account_info(),
+            },
+        ))
+    }
+
+    // CPI Guard blocks the owner from burning, a delegate is still allowed.
+    pub fn burn_tokens(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
+        let all = ctx.accounts;
+
+        burn(
+            CpiContext::new(
+                all.token_2022_program.to_account_info(),
+                Burn {
+                    mint: all.mint.to_account_info(),
+                    from: all.token_account.to_account_info(),
+                    authority: all.authority.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+
+    // CPI Guard blocks the owner from transferring, a delegate is still allowed.
+    pub fn transfer_tokens(ctx: Context<TransferTokens>, amount: u64) -> Result<()> {
+        let all = ctx.accounts;
+
+        transfer_checked(
+            CpiContext::new(
+                all.token_2022_program.to_account_info(),
+                TransferChecked {
+                    from: all.token_account.to_account_info(),
+                    mint: all.mint.to_account_info(),
+                    to: all.to_account.to_account_info(),
+                    authority: all.authority.to_account_info(),
+                },
+            ),
+            amount,
+            all.mint.decimals,
+        )
+    }
 }
 
 #[derive(Accounts)]
@@ -33,3 +134,97 @@ pub struct TransferSol<'info> {
     pub to_wallet: SystemAccount<'info>,
     pub system_program: Program<'info, System>,
 }
+
+#[derive(Accounts)]
+pub struct ApproveDelegate<'info> {
+    #[account(
+        mut,
+        token::authority = owner,
+        token::token_program = Token2022::id()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: any address can be approved as delegate
+    pub delegate: UncheckedAccount<'info>,
+    pub owner: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct SetTokenAuthority<'info> {
+    #[account(
+        mut,
+        token::authority = owner,
+        token::token_program = Token2022::id()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+impl<'info> SetTokenAuthority<'info> {
+    fn set_authority_cpi(&self) -> CpiContext<'_, '_, '_, 'info, SetAuthority<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            SetAuthority {
+                current_authority: self.owner.to_account_info(),
+                account_or_mint: self.token_account.to_account_info(),
+            },
+        )
+    }
+}
+
+#[derive(Accounts)]
+pub struct CloseTokenAccount<'info> {
+    #[account(
+        mut,
+        token::authority = owner,
+        token::token_program = Token2022::id()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: receives the rent lamports of the closed account
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    pub owner: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct BurnTokens<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    // Either the owner or a delegate of the token account.
+    pub authority: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct TransferTokens<'info> {
+    #[account(
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub to_account: InterfaceAccount<'info, TokenAccount>,
+    // Either the owner or a delegate of the token account.
+    pub authority: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
@@ -0,0 +1,170 @@
+// Preconditions checked before Token-2022 CPIs, so a failure comes back as a named
+// error with the offending account logged instead of a raw token program error code.
+use {
+    crate::{error::ErrorCode, utils::expected_transfer_fee},
+    anchor_lang::prelude::*,
+    anchor_spl::token_2022::spl_token_2022::{
+        extension::{
+            memo_transfer::MemoTransfer, non_transferable::NonTransferable, BaseState,
+            BaseStateWithExtensions, Extension, ExtensionType, StateWithExtensions,
+        },
+        state::{Account as AccountState, Mint as MintState},
+    },
+    bytemuck::Pod,
+};
+
+// The account must carry the extension `E`.
+pub(crate) fn check_extension<S: BaseState, E: Extension + Pod>(
+    account: &AccountInfo,
+) -> Result<()> {
+    let data = account.try_borrow_data()?;
+    let state = StateWithExtensions::<S>::unpack(&data)?;
+
+    if state.get_extension::<E>().is_err() {
+        msg!("{} is missing the {:?} extension", account.key, E::TYPE);
+        return err!(ErrorCode::ExtensionMissing);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn check_mint_extension<E: Extension + Pod>(mint: &AccountInfo) -> Result<()> {
+    check_extension::<MintState, E>(mint)
+}
+
+pub(crate) fn check_account_extension<E: Extension + Pod>(
+    token_account: &AccountInfo,
+) -> Result<()> {
+    check_extension::<AccountState, E>(token_account)
+}
+
+// A mint account of `mint_len` bytes must fit the requested mint extensions.
+pub(crate) fn check_mint_len(
+    mint: &AccountInfo,
+    mint_len: u64,
+    extensions: &[ExtensionType],
+) -> Result<()> {
+    let required = ExtensionType::try_calculate_account_len::<MintState>(extensions)?;
+
+    if (mint_len as usize) < required {
+        msg!("{} needs {} bytes, got {}", mint.key, required, mint_len);
+        return err!(ErrorCode::InvalidAccountLength);
+    }
+
+    Ok(())
+}
+
+// A token account of `account_len` bytes must fit the requested extensions along with
+// the ones its mint requires on every account, such as the transfer fee amount.
+pub(crate) fn check_account_len(
+    mint: &AccountInfo,
+    token_account: &AccountInfo,
+    account_len: u64,
+    extensions: &[ExtensionType],
+) -> Result<()> {
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+
+    let mut required_extensions =
+        ExtensionType::get_required_init_account_extensions(&mint_state.get_extension_types()?);
+    for extension in extensions {
+        if !required_extensions.contains(extension) {
+            required_extensions.push(*extension);
+        }
+    }
+    let required = ExtensionType::try_calculate_account_len::<AccountState>(&required_extensions)?;
+
+    if (account_len as usize) < required {
+        msg!(
+            "{} needs {} bytes, got {}",
+            token_account.key,
+            required,
+            account_len
+        );
+        return err!(ErrorCode::InvalidAccountLength);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn check_not_frozen(token_account: &AccountInfo) -> Result<()> {
+    let data = token_account.try_borrow_data()?;
+    let state = StateWithExtensions::<AccountState>::unpack(&data)?;
+
+    if state.base.is_frozen() {
+        msg!("{} is frozen", token_account.key);
+        return err!(ErrorCode::AccountFrozen);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn check_transferable(mint: &AccountInfo) -> Result<()> {
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+
+    if state.get_extension::<NonTransferable>().is_ok() {
+        msg!("{} is non-transferable", mint.key);
+        return err!(ErrorCode::MintNotTransferable);
+    }
+
+    Ok(())
+}
+
+// Token-2022 only accepts a memo issued right before the transfer in the same CPI context.
+pub(crate) fn check_memo_not_required(destination: &AccountInfo) -> Result<()> {
+    let data = destination.try_borrow_data()?;
+    let state = StateWithExtensions::<AccountState>::unpack(&data)?;
+
+    let memo_required = state
+        .get_extension::<MemoTransfer>()
+        .is_ok_and(|memo| bool::from(memo.require_incoming_transfer_memos));
+    if memo_required {
+        msg!("{} requires a memo on incoming transfers", destination.key);
+        return err!(ErrorCode::MemoRequired);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn check_transfer_fee(mint: &AccountInfo, amount: u64, fee: u64) -> Result<()> {
+    let expected = expected_transfer_fee(mint, amount)?;
+
+    if fee != expected {
+        msg!(
+            "{} charges a fee of {} on {}, got {}",
+            mint.key,
+            expected,
+            amount,
+            fee
+        );
+        return err!(ErrorCode::FeeMismatch);
+    }
+
+    Ok(())
+}
+
+// Common checks before moving tokens from `from` to `to`. `with_memo` is set when a memo
+// CPI precedes the transfer, `fee` when the caller states the expected fee.
+pub(crate) fn check_transfer(
+    mint: &AccountInfo,
+    from: &AccountInfo,
+    to: &AccountInfo,
+    amount: u64,
+    fee: Option<u64>,
+    with_memo: bool,
+) -> Result<()> {
+    check_transferable(mint)?;
+    check_not_frozen(from)?;
+    check_not_frozen(to)?;
+
+    if !with_memo {
+        check_memo_not_required(to)?;
+    }
+
+    if let Some(fee) = fee {
+        check_transfer_fee(mint, amount, fee)?;
+    }
+
+    Ok(())
+}
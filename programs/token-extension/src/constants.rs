@@ -2,3 +2,51 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "anchor";
+
+#[constant]
+pub const RATE_GOVERNANCE_SEED: &[u8] = b"rate-governance";
+
+#[constant]
+pub const KYC_CONFIG_SEED: &[u8] = b"kyc-config";
+
+#[constant]
+pub const ACCESS_REQUEST_SEED: &[u8] = b"access-request";
+
+#[constant]
+pub const FREEZE_RECORD_SEED: &[u8] = b"freeze-record";
+
+pub const MAX_MEMO_LEN: usize = 256;
+
+pub const MAX_MEMO_REFERENCE_LEN: usize = 64;
+
+#[constant]
+pub const DELEGATE_GOVERNANCE_SEED: &[u8] = b"delegate-governance";
+
+#[constant]
+pub const DELEGATE_PROPOSAL_SEED: &[u8] = b"delegate-proposal";
+
+pub const MAX_DELEGATE_SIGNERS: usize = 10;
+
+pub const MAX_CREDENTIAL_CLAIMS: usize = 8;
+
+pub const MAX_CLAIM_LEN: usize = 64;
+
+pub const ONE_IN_BASIS_POINTS: f64 = 10_000.;
+
+// Token-2022 counts interest over years of 365.24 days.
+pub const SECONDS_PER_YEAR: f64 = 60. * 60. * 24. * 365.24;
+
+// A full close report of this many accounts still fits the 1024 bytes of return data.
+pub const MAX_CLOSE_ACCOUNTS: usize = 30;
+
+#[constant]
+pub const CREDENTIAL_HOLDER_KEY: &str = "holder";
+
+#[constant]
+pub const CREDENTIAL_EXPIRES_AT_KEY: &str = "expires_at";
+
+#[constant]
+pub const CREDENTIAL_REVOKED_AT_KEY: &str = "revoked_at";
+
+#[constant]
+pub const PROGRAM_CONFIG_SEED: &[u8] = b"program-config";
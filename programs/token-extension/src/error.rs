@@ -10,6 +10,7 @@ pub enum ErrorCode {
     AlreadyInUse,
     #[msg("Five sources are supported as now")]
     InvalidRemainingAccounts,
+    /// Deprecated, no instruction returns it anymore. Kept so the later codes keep their numbers.
     #[msg("Invalid Account State")]
     InvalidAccountState,
     #[msg("ATA is invalid")]
@@ -24,4 +25,96 @@ pub enum ErrorCode {
     InvalidGroupAddress,
     #[msg("Amount is invalid")]
     InvalidAmount,
+    #[msg("Timestamp must not be in the past")]
+    InvalidTimestamp,
+    #[msg("Interest calculation overflow")]
+    InterestOverflow,
+    #[msg("Rate bounds are invalid")]
+    InvalidRateBounds,
+    #[msg("Rate is out of the governance bounds")]
+    RateOutOfBounds,
+    #[msg("Rate change exceeds the maximum change per period")]
+    RateChangeTooLarge,
+    #[msg("Rate change timelock has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("No rate change is pending")]
+    NoPendingRate,
+    #[msg("Signer is not the compliance officer")]
+    InvalidComplianceOfficer,
+    #[msg("Access request is not in a valid state for this action")]
+    InvalidAccessStatus,
+    #[msg("Batch accounts must be pairs of token account and freeze record")]
+    InvalidBatchAccounts,
+    #[msg("Token account belongs to another mint")]
+    MintMismatch,
+    #[msg("Freeze record address is invalid")]
+    InvalidFreezeRecord,
+    #[msg("Authority did not sign and is not a multisig with enough signers")]
+    MissingAuthoritySignature,
+    #[msg("Signer is not the permanent delegate of the mint")]
+    InvalidPermanentDelegate,
+    #[msg("Memo is empty or too long")]
+    InvalidMemo,
+    #[msg("Treasury must differ from the source account")]
+    InvalidTreasury,
+    #[msg("Signer set or threshold is invalid")]
+    InvalidThreshold,
+    #[msg("Signer is not part of the governance signer set")]
+    NotGovernanceSigner,
+    #[msg("Signer already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Proposal has expired")]
+    ProposalExpired,
+    #[msg("Proposal is not active")]
+    ProposalNotActive,
+    #[msg("Proposal does not have enough approvals")]
+    NotEnoughApprovals,
+    #[msg("Accounts do not match the proposal")]
+    ProposalAccountMismatch,
+    #[msg("Next instruction must toggle CPI Guard on the same token account")]
+    MissingCpiGuardToggle,
+    #[msg("Memo reference is empty, too long or has invalid characters")]
+    InvalidMemoReference,
+    #[msg("At least one extension must be requested")]
+    EmptyExtensionList,
+    #[msg("Mint has no close authority")]
+    MissingCloseAuthority,
+    #[msg("Signer is not the close authority of the mint")]
+    InvalidCloseAuthority,
+    #[msg("Mint supply must be zero to close the mint")]
+    MintSupplyNotZero,
+    #[msg("Withheld fees need the withdraw authority and a fee destination")]
+    MissingWithheldFeeAccounts,
+    #[msg("Signer is not the withdraw withheld authority of the mint")]
+    InvalidWithdrawAuthority,
+    #[msg("Mint is not a soulbound credential")]
+    NotACredential,
+    #[msg("Credential was already issued from this mint")]
+    CredentialAlreadyIssued,
+    #[msg("Claims are too many, too long or use a reserved key")]
+    InvalidClaims,
+    #[msg("Delegate account does not match the requested delegate")]
+    InvalidDelegate,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Signer is not the program config admin")]
+    InvalidAdmin,
+    #[msg("Signer is not the pending program config admin")]
+    InvalidPendingAdmin,
+    #[msg("Fee does not match the fee the mint charges on this amount")]
+    FeeMismatch,
+    #[msg("Token account is frozen")]
+    AccountFrozen,
+    #[msg("Account is missing a required extension")]
+    ExtensionMissing,
+    #[msg("Mint is non-transferable")]
+    MintNotTransferable,
+    #[msg("Destination requires a memo on incoming transfers")]
+    MemoRequired,
+    #[msg("Account length is too small for the requested extensions")]
+    InvalidAccountLength,
+    #[msg("Signer is not allowed to perform this action")]
+    Unauthorized,
+    #[msg("Credential was revoked, its mint cannot issue again")]
+    CredentialRevoked,
 }
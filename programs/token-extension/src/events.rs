@@ -0,0 +1,337 @@
+use {
+    crate::{
+        instructions::{AuthorityKind, DefaultState, Field},
+        state::DelegateAction,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[event]
+pub struct AccessRequested {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub wallet: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AccessApproved {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub compliance_officer: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AccessRevoked {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub compliance_officer: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct TokensClawedBack {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub treasury: Pubkey,
+    pub delegate: Pubkey,
+    pub amount: u64,
+    pub reason: String,
+    pub slot: u64,
+}
+
+#[event]
+pub struct CredentialIssued {
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub issuer: Pubkey,
+    pub expires_at: i64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct CredentialRevoked {
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub issuer: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub admin: Pubkey,
+    pub paused: bool,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ProgramConfigInitialized {
+    pub admin: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+    pub slot: u64,
+}
+
+// Extensions are the Token-2022 `ExtensionType` discriminants.
+#[event]
+pub struct MintCreated {
+    pub mint: Pubkey,
+    pub extensions: Vec<u16>,
+    pub mint_authority: Option<Pubkey>,
+    pub freeze_authority: Option<Pubkey>,
+    pub decimals: u8,
+    pub slot: u64,
+}
+
+#[event]
+pub struct MintClosed {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct TokenAccountCreated {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub extensions: Vec<u16>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct TokenAccountsClosed {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub closed: Vec<Pubkey>,
+    pub skipped: Vec<Pubkey>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AccountReallocated {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub extensions: Vec<u16>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct TokensMinted {
+    pub mint: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct TransferExecuted {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub memo: Option<String>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct FeesHarvested {
+    pub mint: Pubkey,
+    pub accounts: Vec<Pubkey>,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub sources: Vec<Pubkey>,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct DefaultStateChanged {
+    pub mint: Pubkey,
+    pub state: DefaultState,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AuthorityChanged {
+    pub mint: Pubkey,
+    pub kind: AuthorityKind,
+    pub previous_authority: Pubkey,
+    pub new_authority: Option<Pubkey>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct KycInitialized {
+    pub mint: Pubkey,
+    pub compliance_officer: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct FreezeStateChanged {
+    pub mint: Pubkey,
+    pub token_accounts: Vec<Pubkey>,
+    pub frozen: bool,
+    pub reason_code: u16,
+    pub slot: u64,
+}
+
+#[event]
+pub struct MemoRequirementChanged {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub required: bool,
+    pub slot: u64,
+}
+
+// Token-2022 only toggles CPI Guard from a top level instruction, so this is emitted before the
+// toggle runs as the next instruction of the same transaction. The transaction fails as a whole if
+// the toggle does.
+#[event]
+pub struct CpiGuardToggleRequested {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub enabled: bool,
+    pub slot: u64,
+}
+
+#[event]
+pub struct InterestRateUpdated {
+    pub mint: Pubkey,
+    pub rate: i16,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RateGovernanceInitialized {
+    pub mint: Pubkey,
+    pub governance: Pubkey,
+    pub min_rate: i16,
+    pub max_rate: i16,
+    pub timelock: i64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RateProposed {
+    pub mint: Pubkey,
+    pub rate: i16,
+    pub executable_at: i64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RateProposalCancelled {
+    pub mint: Pubkey,
+    pub rate: i16,
+    pub slot: u64,
+}
+
+#[event]
+pub struct DelegateBurned {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub delegate: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct DelegateGovernanceInitialized {
+    pub mint: Pubkey,
+    pub governance: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub slot: u64,
+}
+
+#[event]
+pub struct DelegateProposalCreated {
+    pub mint: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub action: DelegateAction,
+    pub slot: u64,
+}
+
+#[event]
+pub struct DelegateProposalApproved {
+    pub mint: Pubkey,
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+    pub approvals: u8,
+    pub slot: u64,
+}
+
+#[event]
+pub struct DelegateProposalCancelled {
+    pub mint: Pubkey,
+    pub proposal: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct DelegateProposalExecuted {
+    pub mint: Pubkey,
+    pub proposal: Pubkey,
+    pub action: DelegateAction,
+    pub slot: u64,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub mint: Pubkey,
+    pub field: Field,
+    pub value: String,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ConfidentialAccountApproved {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ConfidentialMintUpdated {
+    pub mint: Pubkey,
+    pub auto_approve_new_accounts: bool,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ConfidentialFeesHarvested {
+    pub mint: Pubkey,
+    pub accounts: Vec<Pubkey>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ConfidentialFeesWithdrawn {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub sources: Vec<Pubkey>,
+    pub slot: u64,
+}
@@ -0,0 +1,139 @@
+use {
+    crate::{
+        constants::PROGRAM_CONFIG_SEED, error::ErrorCode, events::AuthorityChanged,
+        state::ProgramConfig, utils::authority_signers,
+    },
+    anchor_lang::{prelude::*, solana_program::program::invoke},
+    anchor_spl::token_interface::{Mint, Token2022},
+    spl_token_2022::{
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        instruction::{set_authority, AuthorityType},
+        state::Mint as MintState,
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub enum AuthorityKind {
+    /// Mint new tokens
+    MintTokens,
+    /// Freeze and thaw token accounts
+    FreezeAccount,
+    /// Close the mint once its supply is zero
+    CloseMint,
+    /// Update the transfer fee
+    TransferFeeConfig,
+    /// Withdraw withheld transfer fees
+    WithheldWithdraw,
+    /// Update the interest rate
+    InterestRate,
+    /// Update the metadata pointer
+    MetadataPointer,
+    /// Update the group pointer
+    GroupPointer,
+    /// Update the group member pointer
+    GroupMemberPointer,
+    /// Transfer or burn from any token account of the mint
+    PermanentDelegate,
+}
+
+impl From<AuthorityKind> for AuthorityType {
+    fn from(value: AuthorityKind) -> Self {
+        match value {
+            AuthorityKind::MintTokens => AuthorityType::MintTokens,
+            AuthorityKind::FreezeAccount => AuthorityType::FreezeAccount,
+            AuthorityKind::CloseMint => AuthorityType::CloseMint,
+            AuthorityKind::TransferFeeConfig => AuthorityType::TransferFeeConfig,
+            AuthorityKind::WithheldWithdraw => AuthorityType::WithheldWithdraw,
+            AuthorityKind::InterestRate => AuthorityType::InterestRate,
+            AuthorityKind::MetadataPointer => AuthorityType::MetadataPointer,
+            AuthorityKind::GroupPointer => AuthorityType::GroupPointer,
+            AuthorityKind::GroupMemberPointer => AuthorityType::GroupMemberPointer,
+            AuthorityKind::PermanentDelegate => AuthorityType::PermanentDelegate,
+        }
+    }
+}
+
+impl AuthorityKind {
+    // Mint extension holding the authority, None for the base mint authorities.
+    fn extension(self) -> Option<ExtensionType> {
+        match self {
+            AuthorityKind::MintTokens | AuthorityKind::FreezeAccount => None,
+            AuthorityKind::CloseMint => Some(ExtensionType::MintCloseAuthority),
+            AuthorityKind::TransferFeeConfig | AuthorityKind::WithheldWithdraw => {
+                Some(ExtensionType::TransferFeeConfig)
+            }
+            AuthorityKind::InterestRate => Some(ExtensionType::InterestBearingConfig),
+            AuthorityKind::MetadataPointer => Some(ExtensionType::MetadataPointer),
+            AuthorityKind::GroupPointer => Some(ExtensionType::GroupPointer),
+            AuthorityKind::GroupMemberPointer => Some(ExtensionType::GroupMemberPointer),
+            AuthorityKind::PermanentDelegate => Some(ExtensionType::PermanentDelegate),
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateAuthority<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Current authority, either a signer or a multisig whose signers are passed as remaining accounts
+    pub current_authority: UncheckedAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_set_authority<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateAuthority<'info>>,
+    kind: AuthorityKind,
+    new_authority: Option<Pubkey>,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    if let Some(extension) = kind.extension() {
+        let mint_info = all.mint.to_account_info();
+        let data = mint_info.try_borrow_data()?;
+        let state = StateWithExtensions::<MintState>::unpack(&data)?;
+
+        if !state.get_extension_types()?.contains(&extension) {
+            msg!("{} is missing the {:?} extension", mint_info.key, extension);
+            return err!(ErrorCode::ExtensionMissing);
+        }
+    }
+
+    let signers = authority_signers(&all.current_authority, ctx.remaining_accounts)?;
+    let signer_keys: Vec<&Pubkey> = signers.iter().map(|signer| signer.key).collect();
+
+    let ix = set_authority(
+        all.token_2022_program.key,
+        &all.mint.key(),
+        new_authority.as_ref(),
+        kind.into(),
+        all.current_authority.key,
+        &signer_keys,
+    )?;
+
+    let mut infos = vec![
+        all.mint.to_account_info(),
+        all.current_authority.to_account_info(),
+    ];
+    infos.extend(signers.iter().cloned());
+
+    invoke(&ix, &infos)?;
+
+    emit!(AuthorityChanged {
+        mint: all.mint.key(),
+        kind,
+        previous_authority: all.current_authority.key(),
+        new_authority,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,250 @@
+use {
+    crate::{
+        constants::{FREEZE_RECORD_SEED, PROGRAM_CONFIG_SEED},
+        error::ErrorCode,
+        events::FreezeStateChanged,
+        state::{FreezeRecord, ProgramConfig},
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::{
+            program::{invoke, invoke_signed},
+            system_instruction,
+        },
+        Discriminator,
+    },
+    anchor_spl::{
+        token_2022::{
+            freeze_account,
+            spl_token_2022::{
+                extension::StateWithExtensions,
+                state::{Account as TokenAccountState, AccountState},
+            },
+            thaw_account, FreezeAccount, ThawAccount,
+        },
+        token_interface::{Mint, Token2022},
+    },
+};
+
+#[derive(Accounts)]
+pub struct BatchFreeze<'info> {
+    #[account(
+        mint::token_program = Token2022::id(),
+        mint::freeze_authority = freeze_authority
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub freeze_authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    // Freezing stays available while the program is paused, thaw checks the pause itself.
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+// Remaining accounts are passed as pairs of (token account, freeze record PDA).
+pub(crate) fn handler_to_freeze_accounts<'info>(
+    ctx: Context<'_, '_, '_, 'info, BatchFreeze<'info>>,
+    reason_code: u16,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    for pair in batch_pairs(ctx.remaining_accounts)? {
+        let (token_account, record) = (&pair[0], &pair[1]);
+
+        if token_account_state(token_account, &all.mint.key())? != AccountState::Frozen {
+            freeze_account(CpiContext::new(
+                all.token_2022_program.to_account_info(),
+                FreezeAccount {
+                    account: token_account.to_account_info(),
+                    mint: all.mint.to_account_info(),
+                    authority: all.freeze_authority.to_account_info(),
+                },
+            ))?;
+        }
+
+        write_freeze_record(
+            all,
+            token_account,
+            record,
+            true,
+            reason_code,
+            ctx.program_id,
+        )?;
+    }
+
+    emit!(FreezeStateChanged {
+        mint: all.mint.key(),
+        token_accounts: ctx
+            .remaining_accounts
+            .iter()
+            .step_by(2)
+            .map(|token_account| token_account.key())
+            .collect(),
+        frozen: true,
+        reason_code,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn handler_to_thaw_accounts<'info>(
+    ctx: Context<'_, '_, '_, 'info, BatchFreeze<'info>>,
+    reason_code: u16,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    require!(!all.program_config.paused, ErrorCode::ProgramPaused);
+
+    for pair in batch_pairs(ctx.remaining_accounts)? {
+        let (token_account, record) = (&pair[0], &pair[1]);
+
+        if token_account_state(token_account, &all.mint.key())? == AccountState::Frozen {
+            thaw_account(CpiContext::new(
+                all.token_2022_program.to_account_info(),
+                ThawAccount {
+                    account: token_account.to_account_info(),
+                    mint: all.mint.to_account_info(),
+                    authority: all.freeze_authority.to_account_info(),
+                },
+            ))?;
+        }
+
+        write_freeze_record(
+            all,
+            token_account,
+            record,
+            false,
+            reason_code,
+            ctx.program_id,
+        )?;
+    }
+
+    emit!(FreezeStateChanged {
+        mint: all.mint.key(),
+        token_accounts: ctx
+            .remaining_accounts
+            .iter()
+            .step_by(2)
+            .map(|token_account| token_account.key())
+            .collect(),
+        frozen: false,
+        reason_code,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+fn batch_pairs<'a, 'info>(
+    rem_accs: &'a [AccountInfo<'info>],
+) -> Result<std::slice::ChunksExact<'a, AccountInfo<'info>>> {
+    let pairs = rem_accs.chunks_exact(2);
+    require!(
+        !rem_accs.is_empty() && pairs.remainder().is_empty(),
+        ErrorCode::InvalidBatchAccounts
+    );
+    Ok(pairs)
+}
+
+fn token_account_state(token_account: &AccountInfo, mint: &Pubkey) -> Result<AccountState> {
+    require_keys_eq!(
+        *token_account.owner,
+        Token2022::id(),
+        ErrorCode::InvalidAccountOwner
+    );
+
+    let data = token_account.data.borrow();
+    let account = StateWithExtensions::<TokenAccountState>::unpack(&data)?;
+
+    if account.base.mint != *mint {
+        msg!(
+            "Token account {} belongs to another mint",
+            token_account.key
+        );
+        return err!(ErrorCode::MintMismatch);
+    }
+
+    Ok(account.base.state)
+}
+
+fn write_freeze_record<'info>(
+    all: &BatchFreeze<'info>,
+    token_account: &AccountInfo<'info>,
+    record: &AccountInfo<'info>,
+    frozen: bool,
+    reason_code: u16,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let token_account_key = token_account.key();
+    let (expected, bump) = Pubkey::find_program_address(
+        &[FREEZE_RECORD_SEED, token_account_key.as_ref()],
+        program_id,
+    );
+    require_keys_eq!(record.key(), expected, ErrorCode::InvalidFreezeRecord);
+
+    if record.data_is_empty() {
+        let space = 8 + FreezeRecord::INIT_SPACE;
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[FREEZE_RECORD_SEED, token_account_key.as_ref(), &[bump]]];
+
+        // The record address is predictable and may already hold lamports, which would make
+        // create_account fail, so top up, allocate and assign instead.
+        let missing = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(record.lamports());
+        if missing > 0 {
+            invoke(
+                &system_instruction::transfer(all.payer.key, record.key, missing),
+                &[
+                    all.payer.to_account_info(),
+                    record.to_account_info(),
+                    all.system_program.to_account_info(),
+                ],
+            )?;
+        }
+
+        invoke_signed(
+            &system_instruction::allocate(record.key, space as u64),
+            &[
+                record.to_account_info(),
+                all.system_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+
+        invoke_signed(
+            &system_instruction::assign(record.key, program_id),
+            &[
+                record.to_account_info(),
+                all.system_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+    } else {
+        require_keys_eq!(*record.owner, *program_id, ErrorCode::InvalidFreezeRecord);
+        let data = record.try_borrow_data()?;
+        require!(
+            data.starts_with(&FreezeRecord::DISCRIMINATOR),
+            ErrorCode::InvalidFreezeRecord
+        );
+    }
+
+    let freeze_record = FreezeRecord {
+        mint: all.mint.key(),
+        token_account: token_account_key,
+        frozen,
+        reason_code,
+        updated_by: all.freeze_authority.key(),
+        updated_at: Clock::get()?.unix_timestamp,
+        bump,
+    };
+
+    let mut data = record.try_borrow_mut_data()?;
+    freeze_record.try_serialize(&mut &mut data[..])
+}
@@ -1,8 +1,23 @@
 use {
-    anchor_lang::prelude::*,
+    crate::{
+        constants::PROGRAM_CONFIG_SEED, error::ErrorCode, events::MintClosed, state::ProgramConfig,
+    },
+    anchor_lang::{prelude::*, solana_program::program::invoke},
     anchor_spl::{
         token_2022::{close_account, CloseAccount},
-        token_interface::{Mint, Token2022},
+        token_interface::{
+            spl_token_2022::{
+                extension::{
+                    mint_close_authority::MintCloseAuthority,
+                    transfer_fee::{
+                        instruction::withdraw_withheld_tokens_from_mint, TransferFeeConfig,
+                    },
+                    BaseStateWithExtensions, StateWithExtensions,
+                },
+                state::Mint as MintState,
+            },
+            Mint, Token2022, TokenAccount,
+        },
     },
 };
 
@@ -17,6 +32,12 @@ pub struct CloseMintAccount<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub destination: SystemAccount<'info>,
     pub authority: SystemAccount<'info>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> CloseMintAccount<'info> {
@@ -36,5 +57,136 @@ pub(crate) fn handler(ctx: Context<CloseMintAccount>) -> Result<()> {
     let all = ctx.accounts;
 
     close_account(all.close_account_cpi())?;
+
+    emit!(MintClosed {
+        mint: all.mint.key(),
+        destination: all.destination.key(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SafeCloseMint<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub close_authority: Signer<'info>,
+    pub withdraw_withheld_authority: Option<Signer<'info>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub fee_destination: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> SafeCloseMint<'info> {
+    // Close authority set on the mint and the fees still withheld in it.
+    fn close_authority_and_withheld(&self) -> Result<(Option<Pubkey>, u64, Option<Pubkey>)> {
+        let info = self.mint.to_account_info();
+        let data = info.try_borrow_data()?;
+        let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+
+        let close_authority = mint
+            .get_extension::<MintCloseAuthority>()
+            .ok()
+            .and_then(|extension| Option::<Pubkey>::from(extension.close_authority));
+
+        let (withheld, withdraw_authority) = mint
+            .get_extension::<TransferFeeConfig>()
+            .map(|config| {
+                (
+                    u64::from(config.withheld_amount),
+                    Option::<Pubkey>::from(config.withdraw_withheld_authority),
+                )
+            })
+            .unwrap_or((0, None));
+
+        Ok((close_authority, withheld, withdraw_authority))
+    }
+
+    fn withdraw_withheld(&self, withdraw_authority: Option<Pubkey>) -> Result<()> {
+        let (Some(authority), Some(fee_destination)) = (
+            self.withdraw_withheld_authority.as_ref(),
+            self.fee_destination.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingWithheldFeeAccounts);
+        };
+        require!(
+            withdraw_authority == Some(authority.key()),
+            ErrorCode::InvalidWithdrawAuthority
+        );
+
+        let ix = withdraw_withheld_tokens_from_mint(
+            self.token_2022_program.key,
+            &self.mint.key(),
+            &fee_destination.key(),
+            authority.key,
+            &[],
+        )?;
+
+        invoke(
+            &ix,
+            &[
+                self.mint.to_account_info(),
+                fee_destination.to_account_info(),
+                authority.to_account_info(),
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    fn close_account_cpi(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            CloseAccount {
+                account: self.mint.to_account_info(),
+                destination: self.destination.to_account_info(),
+                authority: self.close_authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler_to_safe_close_mint(ctx: Context<SafeCloseMint>) -> Result<()> {
+    let all = ctx.accounts;
+
+    let (close_authority, withheld, withdraw_authority) = all.close_authority_and_withheld()?;
+
+    let close_authority = close_authority.ok_or(ErrorCode::MissingCloseAuthority)?;
+    require_keys_eq!(
+        close_authority,
+        all.close_authority.key(),
+        ErrorCode::InvalidCloseAuthority
+    );
+
+    if withheld > 0 {
+        all.withdraw_withheld(withdraw_authority)?;
+    }
+
+    require!(all.mint.supply == 0, ErrorCode::MintSupplyNotZero);
+
+    close_account(all.close_account_cpi())?;
+
+    emit!(MintClosed {
+        mint: all.mint.key(),
+        destination: all.destination.key(),
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
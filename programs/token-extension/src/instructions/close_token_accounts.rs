@@ -0,0 +1,219 @@
+use {
+    crate::{
+        constants::{MAX_CLOSE_ACCOUNTS, PROGRAM_CONFIG_SEED},
+        error::ErrorCode,
+        events::TokenAccountsClosed,
+        state::ProgramConfig,
+    },
+    anchor_lang::{prelude::*, solana_program::program::invoke},
+    anchor_spl::{
+        token_2022::{close_account, CloseAccount},
+        token_interface::{
+            spl_token_2022::{
+                extension::{
+                    confidential_transfer::ConfidentialTransferAccount,
+                    cpi_guard::CpiGuard,
+                    transfer_fee::{
+                        instruction::harvest_withheld_tokens_to_mint, TransferFeeAmount,
+                    },
+                    BaseStateWithExtensions, StateWithExtensions,
+                },
+                state::Account as AccountState,
+            },
+            Mint, Token2022,
+        },
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CloseSkipReason {
+    /// Not a Token-2022 token account
+    InvalidAccount,
+    /// Token account belongs to another mint
+    MintMismatch,
+    /// Signer is neither the close authority nor the owner
+    NotAuthority,
+    /// Token account still holds tokens
+    NonZeroBalance,
+    /// Withheld fees could not be harvested to the mint
+    WithheldFees,
+    /// Confidential balances are not empty
+    ConfidentialBalance,
+    /// CPI Guard only lets a CPI close the account to its owner
+    CpiGuardLocked,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SkippedAccount {
+    pub account: Pubkey,
+    pub reason: CloseSkipReason,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct CloseReport {
+    /// Accounts closed, their lamports went to the destination
+    pub closed: Vec<Pubkey>,
+    /// Accounts left open and why
+    pub skipped: Vec<SkippedAccount>,
+}
+
+#[derive(Accounts)]
+pub struct CloseTokenAccounts<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> CloseTokenAccounts<'info> {
+    // Whether `token_account` belongs to the mint and withholds fees, whoever owns it and
+    // whatever its balance.
+    fn has_withheld_fees(&self, token_account: &AccountInfo) -> bool {
+        if token_account.owner != &Token2022::id() || !token_account.is_writable {
+            return false;
+        }
+        let Ok(data) = token_account.try_borrow_data() else {
+            return false;
+        };
+        let Ok(account) = StateWithExtensions::<AccountState>::unpack(&data) else {
+            return false;
+        };
+
+        account.base.mint == self.mint.key()
+            && account
+                .get_extension::<TransferFeeAmount>()
+                .is_ok_and(|fee| u64::from(fee.withheld_amount) > 0)
+    }
+
+    // Reason `token_account` cannot be closed by the owner, if any.
+    fn skip_reason(&self, token_account: &AccountInfo) -> Option<CloseSkipReason> {
+        if token_account.owner != &Token2022::id() || !token_account.is_writable {
+            return Some(CloseSkipReason::InvalidAccount);
+        }
+        let Ok(data) = token_account.try_borrow_data() else {
+            return Some(CloseSkipReason::InvalidAccount);
+        };
+        let Ok(account) = StateWithExtensions::<AccountState>::unpack(&data) else {
+            return Some(CloseSkipReason::InvalidAccount);
+        };
+
+        if account.base.mint != self.mint.key() {
+            return Some(CloseSkipReason::MintMismatch);
+        }
+        let authority =
+            Option::<Pubkey>::from(account.base.close_authority).unwrap_or(account.base.owner);
+        if authority != self.owner.key() {
+            return Some(CloseSkipReason::NotAuthority);
+        }
+        if !account.base.is_native() && account.base.amount != 0 {
+            return Some(CloseSkipReason::NonZeroBalance);
+        }
+        if account
+            .get_extension::<TransferFeeAmount>()
+            .is_ok_and(|fee| fee.closable().is_err())
+        {
+            return Some(CloseSkipReason::WithheldFees);
+        }
+        if account
+            .get_extension::<ConfidentialTransferAccount>()
+            .is_ok_and(|confidential| confidential.closable().is_err())
+        {
+            return Some(CloseSkipReason::ConfidentialBalance);
+        }
+        if account
+            .get_extension::<CpiGuard>()
+            .is_ok_and(|guard| bool::from(guard.lock_cpi))
+            && self.destination.key() != account.base.owner
+        {
+            return Some(CloseSkipReason::CpiGuardLocked);
+        }
+
+        None
+    }
+
+    fn close_account_cpi(
+        &self,
+        token_account: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            CloseAccount {
+                account: token_account,
+                destination: self.destination.to_account_info(),
+                authority: self.owner.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler_to_close_token_accounts<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseTokenAccounts<'info>>,
+) -> Result<CloseReport> {
+    let all = ctx.accounts;
+
+    // The report is returned through return data, capped at 1024 bytes.
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() <= MAX_CLOSE_ACCOUNTS,
+        ErrorCode::InvalidRemainingAccounts
+    );
+
+    // Harvesting is permissionless, so fees withheld in accounts left open are collected too.
+    let harvest_sources: Vec<&AccountInfo<'info>> = ctx
+        .remaining_accounts
+        .iter()
+        .filter(|token_account| all.has_withheld_fees(token_account))
+        .collect();
+
+    if !harvest_sources.is_empty() {
+        let sources: Vec<&Pubkey> = harvest_sources.iter().map(|source| source.key).collect();
+        let ix =
+            harvest_withheld_tokens_to_mint(all.token_2022_program.key, &all.mint.key(), &sources)?;
+
+        let mut infos = vec![all.mint.to_account_info()];
+        infos.extend(harvest_sources.iter().map(|source| (*source).clone()));
+
+        invoke(&ix, &infos)?;
+    }
+
+    let mut report = CloseReport::default();
+    for token_account in ctx.remaining_accounts {
+        match all.skip_reason(token_account) {
+            Some(reason) => {
+                msg!("Skipping {}: {:?}", token_account.key, reason);
+                report.skipped.push(SkippedAccount {
+                    account: token_account.key(),
+                    reason,
+                });
+            }
+            None => {
+                close_account(all.close_account_cpi(token_account.clone()))?;
+                report.closed.push(token_account.key());
+            }
+        }
+    }
+
+    emit!(TokenAccountsClosed {
+        mint: all.mint.key(),
+        owner: all.owner.key(),
+        closed: report.closed.clone(),
+        skipped: report
+            .skipped
+            .iter()
+            .map(|skipped| skipped.account)
+            .collect(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(report)
+}
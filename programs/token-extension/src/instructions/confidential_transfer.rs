@@ -1,14 +1,29 @@
 use {
-    crate::error::ErrorCode,
+    crate::{
+        checks::{check_account_len, check_mint_len},
+        constants::PROGRAM_CONFIG_SEED,
+        error::ErrorCode,
+        events::{ConfidentialAccountApproved, ConfidentialMintUpdated},
+        state::ProgramConfig,
+        utils::{emit_mint_created, emit_token_account_created},
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{program::invoke, system_instruction},
     },
     anchor_spl::{
-        token_2022::{initialize_account3, initialize_mint2, InitializeAccount3, InitializeMint2},
-        token_interface::{Mint, Token2022},
+        token_2022::{
+            initialize_account3, initialize_mint2, spl_token_2022::extension::ExtensionType,
+            InitializeAccount3, InitializeMint2,
+        },
+        token_interface::{Mint, Token2022, TokenAccount},
+    },
+    spl_token_2022::{
+        extension::confidential_transfer::instruction::{
+            approve_account, initialize_mint, update_mint,
+        },
+        solana_zk_token_sdk::zk_token_elgamal::pod::ElGamalPubkey,
     },
-    spl_token_2022::extension::confidential_transfer::instruction::initialize_mint,
 };
 
 #[derive(Accounts)]
@@ -25,6 +40,12 @@ pub struct InitializeConfidentialMint<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> InitializeConfidentialMint<'info> {
@@ -42,9 +63,17 @@ pub(crate) fn handler_to_initialize_confidential_mint(
     ctx: Context<InitializeConfidentialMint>,
     mint_len: u64,
     decimals: u8,
+    auto_approve_new_accounts: bool,
+    auditor_elgamal_pubkey: Option<[u8; 32]>,
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_mint_len(
+        &all.mint.to_account_info(),
+        mint_len,
+        &[ExtensionType::ConfidentialTransferMint],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -60,8 +89,8 @@ pub(crate) fn handler_to_initialize_confidential_mint(
         all.token_2022_program.key,
         all.mint.key,
         Some(all.all_mint_role.key()),
-        true,
-        None,
+        auto_approve_new_accounts,
+        auditor_elgamal_pubkey.map(ElGamalPubkey),
     )?;
 
     invoke(
@@ -79,6 +108,9 @@ pub(crate) fn handler_to_initialize_confidential_mint(
         all.all_mint_role.key,
         None,
     )?;
+
+    emit_mint_created(&all.mint)?;
+
     Ok(())
 }
 
@@ -101,6 +133,12 @@ pub struct InitializeConfidentialAccount<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> InitializeConfidentialAccount<'info> {
@@ -122,6 +160,13 @@ pub(crate) fn handler_to_initialize_confidential_account(
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_account_len(
+        &all.mint.to_account_info(),
+        &all.token_account.to_account_info(),
+        account_len,
+        &[],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -140,5 +185,112 @@ pub(crate) fn handler_to_initialize_confidential_account(
 
     //TODO: Work in progress
 
+    emit_token_account_created(&all.token_account)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveConfidentialAccount<'info> {
+    #[account(
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub confidential_authority: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_approve_confidential_account(
+    ctx: Context<ApproveConfidentialAccount>,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    let ix = approve_account(
+        all.token_2022_program.key,
+        &all.token_account.key(),
+        &all.mint.key(),
+        all.confidential_authority.key, // gets validated here
+        &[],
+    )?;
+
+    invoke(
+        &ix,
+        &[
+            all.token_2022_program.to_account_info(),
+            all.token_account.to_account_info(),
+            all.mint.to_account_info(),
+            all.confidential_authority.to_account_info(),
+        ],
+    )?;
+
+    emit!(ConfidentialAccountApproved {
+        mint: all.mint.key(),
+        token_account: all.token_account.key(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfidentialMint<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub confidential_authority: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_update_confidential_mint(
+    ctx: Context<UpdateConfidentialMint>,
+    auto_approve_new_accounts: bool,
+    auditor_elgamal_pubkey: Option<[u8; 32]>,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    let ix = update_mint(
+        all.token_2022_program.key,
+        &all.mint.key(),
+        all.confidential_authority.key, // gets validated here
+        &[],
+        auto_approve_new_accounts,
+        auditor_elgamal_pubkey.map(ElGamalPubkey),
+    )?;
+
+    invoke(
+        &ix,
+        &[
+            all.token_2022_program.to_account_info(),
+            all.mint.to_account_info(),
+            all.confidential_authority.to_account_info(),
+        ],
+    )?;
+
+    emit!(ConfidentialMintUpdated {
+        mint: all.mint.key(),
+        auto_approve_new_accounts,
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
@@ -0,0 +1,375 @@
+use {
+    crate::{
+        checks::check_mint_len,
+        constants::PROGRAM_CONFIG_SEED,
+        error::ErrorCode,
+        events::{ConfidentialFeesHarvested, ConfidentialFeesWithdrawn},
+        state::ProgramConfig,
+        utils::emit_mint_created,
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::{program::invoke, system_instruction},
+    },
+    anchor_spl::{
+        token_2022::{initialize_mint2, spl_token_2022::extension::ExtensionType, InitializeMint2},
+        token_interface::{Mint, Token2022, TokenAccount},
+    },
+    spl_token_2022::{
+        extension::{
+            confidential_transfer::instruction::initialize_mint,
+            confidential_transfer_fee::{
+                instruction::{
+                    harvest_withheld_tokens_to_mint, initialize_confidential_transfer_fee_config,
+                    inner_withdraw_withheld_tokens_from_accounts,
+                    inner_withdraw_withheld_tokens_from_mint,
+                },
+                ConfidentialTransferFeeAmount,
+            },
+            transfer_fee::instruction::initialize_transfer_fee_config,
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+        proof::ProofLocation,
+        solana_zk_token_sdk::zk_token_elgamal::pod::{AeCiphertext, ElGamalPubkey},
+    },
+};
+
+#[derive(Accounts)]
+pub struct InitializeConfidentialTransferFeeMint<'info> {
+    #[account(
+        mut,
+        owner = System::id() @ ErrorCode::InvalidAccountOwner,
+        constraint = mint.data_is_empty() @ ErrorCode::AlreadyInUse
+    )]
+    pub mint: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub all_mint_role: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> InitializeConfidentialTransferFeeMint<'info> {
+    fn initialize_mint_2_cpi(&self) -> CpiContext<'_, '_, '_, 'info, InitializeMint2<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            InitializeMint2 {
+                mint: self.mint.to_account_info(),
+            },
+        )
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn handler_to_initialize_confidential_transfer_fee_mint(
+    ctx: Context<InitializeConfidentialTransferFeeMint>,
+    mint_len: u64,
+    decimals: u8,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+    auto_approve_new_accounts: bool,
+    auditor_elgamal_pubkey: Option<[u8; 32]>,
+    withdraw_withheld_authority_elgamal_pubkey: [u8; 32],
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    check_mint_len(
+        &all.mint.to_account_info(),
+        mint_len,
+        &[
+            ExtensionType::TransferFeeConfig,
+            ExtensionType::ConfidentialTransferMint,
+            ExtensionType::ConfidentialTransferFeeConfig,
+        ],
+    )?;
+
+    invoke(
+        &system_instruction::create_account(
+            all.payer.key,
+            all.mint.key,
+            Rent::get()?.minimum_balance(mint_len as usize),
+            mint_len,
+            all.token_2022_program.key,
+        ),
+        &[all.payer.to_account_info(), all.mint.to_account_info()],
+    )?;
+
+    let ix = initialize_transfer_fee_config(
+        all.token_2022_program.key,
+        all.mint.key,
+        Some(all.all_mint_role.key),
+        Some(all.all_mint_role.key),
+        transfer_fee_basis_points,
+        maximum_fee,
+    )?;
+
+    invoke(
+        &ix,
+        &[
+            all.token_2022_program.to_account_info(),
+            all.mint.to_account_info(),
+        ],
+    )?;
+
+    let ix = initialize_mint(
+        all.token_2022_program.key,
+        all.mint.key,
+        Some(all.all_mint_role.key()),
+        auto_approve_new_accounts,
+        auditor_elgamal_pubkey.map(ElGamalPubkey),
+    )?;
+
+    invoke(
+        &ix,
+        &[
+            all.token_2022_program.to_account_info(),
+            all.mint.to_account_info(),
+        ],
+    )?;
+
+    let ix = initialize_confidential_transfer_fee_config(
+        all.token_2022_program.key,
+        all.mint.key,
+        Some(all.all_mint_role.key()),
+        ElGamalPubkey(withdraw_withheld_authority_elgamal_pubkey),
+    )?;
+
+    invoke(
+        &ix,
+        &[
+            all.token_2022_program.to_account_info(),
+            all.mint.to_account_info(),
+        ],
+    )?;
+
+    initialize_mint2(
+        all.initialize_mint_2_cpi(),
+        decimals,
+        all.all_mint_role.key,
+        None,
+    )?;
+
+    emit_mint_created(&all.mint)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct HarvestConfidentialWithheldToken<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_harvest_confidential_withheld_token<'a>(
+    ctx: Context<'_, '_, '_, 'a, HarvestConfidentialWithheldToken<'a>>,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    let (sources, mut infos) =
+        filter_confidential_sources_account_info(ctx.remaining_accounts, &all.mint.key())?;
+
+    let ix =
+        harvest_withheld_tokens_to_mint(all.token_2022_program.key, &all.mint.key(), &sources)?;
+
+    infos.push(all.token_2022_program.to_account_info());
+    infos.push(all.mint.to_account_info());
+
+    invoke(&ix, &infos)?;
+
+    emit!(ConfidentialFeesHarvested {
+        mint: all.mint.key(),
+        accounts: sources.into_iter().copied().collect(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawConfidentialWithheldMint<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Pre-verified ciphertext-ciphertext equality proof context, validated by token program
+    pub proof_context: UncheckedAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_withdraw_confidential_withheld_mint(
+    ctx: Context<WithdrawConfidentialWithheldMint>,
+    new_decryptable_available_balance: [u8; 36],
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    let ix = inner_withdraw_withheld_tokens_from_mint(
+        all.token_2022_program.key,
+        &all.mint.key(),
+        &all.destination.key(),
+        &AeCiphertext(new_decryptable_available_balance),
+        all.authority.key,
+        &[],
+        ProofLocation::ContextStateAccount(all.proof_context.key),
+    )?;
+
+    invoke(
+        &ix,
+        &[
+            all.token_2022_program.to_account_info(),
+            all.mint.to_account_info(),
+            all.destination.to_account_info(),
+            all.proof_context.to_account_info(),
+            all.authority.to_account_info(),
+        ],
+    )?;
+
+    emit!(ConfidentialFeesWithdrawn {
+        mint: all.mint.key(),
+        destination: all.destination.key(),
+        sources: vec![all.mint.key()],
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawConfidentialWithheldAccount<'info> {
+    #[account(
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Pre-verified ciphertext-ciphertext equality proof context, validated by token program
+    pub proof_context: UncheckedAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_withdraw_confidential_withheld_account<'a>(
+    ctx: Context<'_, '_, '_, 'a, WithdrawConfidentialWithheldAccount<'a>>,
+    new_decryptable_available_balance: [u8; 36],
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    let (sources, mut infos) =
+        filter_confidential_sources_account_info(ctx.remaining_accounts, &all.mint.key())?;
+
+    let ix = inner_withdraw_withheld_tokens_from_accounts(
+        all.token_2022_program.key,
+        &all.mint.key(),
+        &all.destination.key(),
+        &AeCiphertext(new_decryptable_available_balance),
+        all.authority.key,
+        &[],
+        &sources,
+        ProofLocation::ContextStateAccount(all.proof_context.key),
+    )?;
+
+    infos.push(all.token_2022_program.to_account_info());
+    infos.push(all.mint.to_account_info());
+    infos.push(all.destination.to_account_info());
+    infos.push(all.proof_context.to_account_info());
+    infos.push(all.authority.to_account_info());
+
+    invoke(&ix, &infos)?;
+
+    emit!(ConfidentialFeesWithdrawn {
+        mint: all.mint.key(),
+        destination: all.destination.key(),
+        sources: sources.into_iter().copied().collect(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+// Encrypted withheld amounts can't be compared against zero on-chain, so every source must be a
+// token-2022 account of `mint` carrying the confidential fee extension. Anything else fails the
+// instruction rather than being dropped, so the event always lists every account passed in.
+fn filter_confidential_sources_account_info<'a, 'info>(
+    rem_accs: &'a [AccountInfo<'info>],
+    mint: &Pubkey,
+) -> Result<(Vec<&'a Pubkey>, Vec<AccountInfo<'info>>)> {
+    require!(
+        !rem_accs.is_empty() && rem_accs.len() <= 5,
+        ErrorCode::InvalidRemainingAccounts
+    );
+
+    let mut sources = Vec::new();
+    let mut acc_infos = Vec::new();
+
+    for acc_info in rem_accs {
+        require_keys_eq!(
+            acc_info.owner.key(),
+            Token2022::id(),
+            ErrorCode::InvalidAccountOwner
+        );
+
+        let account_data = acc_info.data.borrow();
+        let token_account =
+            StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account_data)?;
+
+        if token_account.base.mint != *mint {
+            msg!("Token account {} belongs to another mint", acc_info.key);
+            return err!(ErrorCode::MintMismatch);
+        }
+
+        if token_account
+            .get_extension::<ConfidentialTransferFeeAmount>()
+            .is_err()
+        {
+            msg!(
+                "{} is missing the ConfidentialTransferFeeAmount extension",
+                acc_info.key
+            );
+            return err!(ErrorCode::ExtensionMissing);
+        }
+
+        sources.push(acc_info.key);
+        acc_infos.push(acc_info.to_account_info());
+    }
+    Ok((sources, acc_infos))
+}
@@ -1,12 +1,32 @@
 use {
-    crate::error::ErrorCode,
+    crate::{
+        checks::{check_account_extension, check_account_len, check_transfer},
+        constants::PROGRAM_CONFIG_SEED,
+        error::ErrorCode,
+        events::{CpiGuardToggleRequested, TransferExecuted},
+        state::ProgramConfig,
+        utils::{check_next_cpi_guard_toggle, emit_token_account_created, expected_transfer_fee},
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{native_token::sol_to_lamports, program::invoke, system_instruction},
     },
     anchor_spl::{
         token_2022::{initialize_account3, transfer_checked, InitializeAccount3, TransferChecked},
-        token_interface::{Mint, Token2022, TokenAccount},
+        token_interface::{
+            spl_token_2022::{
+                extension::{
+                    cpi_guard::{
+                        instruction::{disable_cpi_guard, enable_cpi_guard},
+                        CpiGuard,
+                    },
+                    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+                },
+                instruction::reallocate,
+                state::Account as AccountState,
+            },
+            Mint, Token2022, TokenAccount,
+        },
     },
     opaque::{
         cpi::{accounts::TransferSol, transfer_sol},
@@ -33,6 +53,12 @@ pub struct IntializeTokenAccount<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> IntializeTokenAccount<'info> {
@@ -54,6 +80,13 @@ pub(crate) fn handler_to_initialize_token_account(
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_account_len(
+        &all.mint.to_account_info(),
+        &all.token_acc.to_account_info(),
+        account_len,
+        &[],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -69,7 +102,11 @@ pub(crate) fn handler_to_initialize_token_account(
         ],
     )?;
 
-    initialize_account3(all.initialize_account3_cpi())
+    initialize_account3(all.initialize_account3_cpi())?;
+
+    emit_token_account_created(&all.token_acc)?;
+
+    Ok(())
 }
 
 #[derive(Accounts)]
@@ -98,6 +135,12 @@ pub struct TransferToken<'info> {
     pub bad_wallet: SystemAccount<'info>,
     pub opaque: Program<'info, Opaque>,
     pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> TransferToken<'info> {
@@ -132,8 +175,136 @@ pub(crate) fn handler_to_transfer_token(
 ) -> Result<()> {
     let all = ctx.accounts;
     require!(amount > 0, ErrorCode::InvalidAmount);
+    check_transfer(
+        &all.mint.to_account_info(),
+        &all.from_acc.to_account_info(),
+        &all.to_acc.to_account_info(),
+        amount,
+        None,
+        false,
+    )?;
 
     transfer_sol(all.transfer_sol_cpi(), sol_to_lamports(1.1))?;
     transfer_checked(all.transfer_checked_cpi(), amount, decimals)?;
+
+    emit!(TransferExecuted {
+        mint: all.mint.key(),
+        from: all.from_acc.key(),
+        to: all.to_acc.key(),
+        amount,
+        fee: expected_transfer_fee(&all.mint.to_account_info(), amount)?,
+        memo: None,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ToggleCpiGuard<'info> {
+    #[account(
+        mut,
+        token::authority = owner,
+        token::token_program = Token2022::id()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: address is checked against the instructions sysvar id
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> ToggleCpiGuard<'info> {
+    fn has_cpi_guard(&self) -> Result<bool> {
+        let data = self.token_account.to_account_info();
+        let data = data.try_borrow_data()?;
+        let state = StateWithExtensions::<AccountState>::unpack(&data)?;
+        Ok(state.get_extension::<CpiGuard>().is_ok())
+    }
+
+    fn realloc_cpi_guard(&self) -> Result<()> {
+        if self.has_cpi_guard()? {
+            return Ok(());
+        }
+
+        let ix = reallocate(
+            self.token_2022_program.key,
+            &self.token_account.key(),
+            self.payer.key,
+            self.owner.key,
+            &[],
+            &[ExtensionType::CpiGuard],
+        )?;
+
+        invoke(
+            &ix,
+            &[
+                self.token_account.to_account_info(),
+                self.payer.to_account_info(),
+                self.system_program.to_account_info(),
+                self.owner.to_account_info(),
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    fn check_next_toggle(&self, enable: bool) -> Result<()> {
+        let toggle = if enable {
+            enable_cpi_guard
+        } else {
+            disable_cpi_guard
+        };
+        let expected = toggle(
+            self.token_2022_program.key,
+            &self.token_account.key(),
+            self.owner.key,
+            &[],
+        )?;
+
+        check_next_cpi_guard_toggle(&self.instructions.to_account_info(), &expected)
+    }
+}
+
+pub(crate) fn handler_to_enable_cpi_guard(ctx: Context<ToggleCpiGuard>) -> Result<()> {
+    let all = ctx.accounts;
+
+    all.check_next_toggle(true)?;
+    all.realloc_cpi_guard()?;
+
+    emit!(CpiGuardToggleRequested {
+        mint: all.token_account.mint,
+        token_account: all.token_account.key(),
+        enabled: true,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+// Disabling never needs space, the account only has to carry the extension already.
+pub(crate) fn handler_to_disable_cpi_guard(ctx: Context<ToggleCpiGuard>) -> Result<()> {
+    let all = ctx.accounts;
+
+    all.check_next_toggle(false)?;
+    check_account_extension::<CpiGuard>(&all.token_account.to_account_info())?;
+
+    emit!(CpiGuardToggleRequested {
+        mint: all.token_account.mint,
+        token_account: all.token_account.key(),
+        enabled: false,
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
@@ -0,0 +1,218 @@
+use {
+    crate::{
+        constants::PROGRAM_CONFIG_SEED,
+        error::ErrorCode,
+        state::ProgramConfig,
+        utils::{check_next_cpi_guard_toggle, emit_token_account_created},
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::{program::invoke, system_instruction},
+    },
+    anchor_spl::{
+        token_2022::{
+            approve, initialize_account3, initialize_immutable_owner, Approve, InitializeAccount3,
+            InitializeImmutableOwner,
+        },
+        token_interface::{
+            spl_token_2022::{
+                extension::{
+                    cpi_guard::instruction::enable_cpi_guard,
+                    memo_transfer::instruction::enable_required_transfer_memos,
+                    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+                },
+                state::{Account as AccountState, Mint as MintState},
+            },
+            Mint, Token2022,
+        },
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct TokenAccountOptions {
+    /// Lock the owner, always on when the mint requires it
+    pub immutable_owner: bool,
+    /// Require a memo on incoming transfers
+    pub memo_transfer: bool,
+    /// Lock owner actions made through CPI, the Token-2022 enable
+    /// instruction must follow in the same transaction
+    pub cpi_guard: bool,
+    /// Delegate approved right after initialization
+    pub delegate: Option<Pubkey>,
+    /// Amount the delegate may move
+    pub delegated_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct CreateTokenAccount<'info> {
+    #[account(
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        owner = System::id() @ ErrorCode::InvalidAccountOwner,
+        constraint = token_account.data_is_empty() @ ErrorCode::AlreadyInUse
+    )]
+    pub token_account: Signer<'info>,
+    pub owner: Signer<'info>,
+    /// CHECK: Any wallet, only needed when a delegate is approved
+    pub delegate: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: address is checked against the instructions sysvar id
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> CreateTokenAccount<'info> {
+    // Extensions the new account needs: the ones asked for plus the ones the mint requires.
+    fn extension_types(&self, options: &TokenAccountOptions) -> Result<Vec<ExtensionType>> {
+        let info = self.mint.to_account_info();
+        let data = info.try_borrow_data()?;
+        let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+
+        let mut extension_types =
+            ExtensionType::get_required_init_account_extensions(&mint.get_extension_types()?);
+        if options.immutable_owner {
+            extension_types.push(ExtensionType::ImmutableOwner);
+        }
+        if options.memo_transfer {
+            extension_types.push(ExtensionType::MemoTransfer);
+        }
+        if options.cpi_guard {
+            extension_types.push(ExtensionType::CpiGuard);
+        }
+
+        Ok(extension_types)
+    }
+
+    fn initialize_immutable_owner_cpi(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, InitializeImmutableOwner<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            InitializeImmutableOwner {
+                account: self.token_account.to_account_info(),
+            },
+        )
+    }
+
+    fn initialize_account3_cpi(&self) -> CpiContext<'_, '_, '_, 'info, InitializeAccount3<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            InitializeAccount3 {
+                mint: self.mint.to_account_info(),
+                account: self.token_account.to_account_info(),
+                authority: self.owner.to_account_info(),
+            },
+        )
+    }
+
+    fn approve_cpi(
+        &self,
+        delegate: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Approve<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            Approve {
+                to: self.token_account.to_account_info(),
+                delegate,
+                authority: self.owner.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler_to_create_token_account(
+    ctx: Context<CreateTokenAccount>,
+    options: TokenAccountOptions,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    // A delegate account or amount without a requested delegate would be silently ignored.
+    if options.delegate.is_none() {
+        require!(
+            all.delegate.is_none() && options.delegated_amount == 0,
+            ErrorCode::InvalidDelegate
+        );
+    }
+
+    let extension_types = all.extension_types(&options)?;
+    let account_len = ExtensionType::try_calculate_account_len::<AccountState>(&extension_types)?;
+
+    invoke(
+        &system_instruction::create_account(
+            all.payer.key,
+            all.token_account.key,
+            Rent::get()?.minimum_balance(account_len),
+            account_len as u64,
+            &Token2022::id(),
+        ),
+        &[
+            all.payer.to_account_info(),
+            all.token_account.to_account_info(),
+        ],
+    )?;
+
+    // Immutable owner must be set before the account is initialized.
+    if extension_types.contains(&ExtensionType::ImmutableOwner) {
+        initialize_immutable_owner(all.initialize_immutable_owner_cpi())?;
+    }
+
+    initialize_account3(all.initialize_account3_cpi())?;
+
+    if options.memo_transfer {
+        let ix = enable_required_transfer_memos(
+            all.token_2022_program.key,
+            all.token_account.key,
+            all.owner.key,
+            &[],
+        )?;
+
+        invoke(
+            &ix,
+            &[
+                all.token_account.to_account_info(),
+                all.owner.to_account_info(),
+            ],
+        )?;
+    }
+
+    // Approve before CPI Guard is turned on, it blocks approvals made through CPI.
+    if let Some(delegate_key) = options.delegate {
+        let delegate = all
+            .delegate
+            .as_ref()
+            .filter(|delegate| delegate.key() == delegate_key)
+            .ok_or(ErrorCode::InvalidDelegate)?;
+
+        approve(
+            all.approve_cpi(delegate.to_account_info()),
+            options.delegated_amount,
+        )?;
+    }
+
+    if options.cpi_guard {
+        let ix = enable_cpi_guard(
+            all.token_2022_program.key,
+            all.token_account.key,
+            all.owner.key,
+            &[],
+        )?;
+
+        check_next_cpi_guard_toggle(&all.instructions.to_account_info(), &ix)?;
+    }
+
+    emit_token_account_created(&all.token_account)?;
+
+    Ok(())
+}
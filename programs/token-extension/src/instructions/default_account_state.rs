@@ -1,5 +1,12 @@
 use {
-    crate::error::ErrorCode,
+    crate::{
+        checks::{check_mint_extension, check_mint_len},
+        constants::PROGRAM_CONFIG_SEED,
+        error::ErrorCode,
+        events::DefaultStateChanged,
+        state::ProgramConfig,
+        utils::{authority_signers, emit_mint_created},
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{program::invoke, system_instruction},
@@ -8,9 +15,11 @@ use {
         token_2022::{
             self, initialize_mint2,
             spl_token_2022::{
-                extension::default_account_state::instruction::{
-                    initialize_default_account_state, update_default_account_state,
+                extension::default_account_state::{
+                    instruction::{initialize_default_account_state, update_default_account_state},
+                    DefaultAccountState as DefaultAccountStateExtension,
                 },
+                extension::ExtensionType,
                 state::AccountState,
             },
         },
@@ -18,6 +27,23 @@ use {
     },
 };
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub enum DefaultState {
+    /// New token accounts are usable right away
+    Initialized,
+    /// New token accounts must be thawed by the freeze authority
+    Frozen,
+}
+
+impl From<DefaultState> for AccountState {
+    fn from(value: DefaultState) -> Self {
+        match value {
+            DefaultState::Initialized => AccountState::Initialized,
+            DefaultState::Frozen => AccountState::Frozen,
+        }
+    }
+}
+
 #[derive(Accounts)]
 pub struct DefaultAccountState<'info> {
     #[account(
@@ -32,6 +58,12 @@ pub struct DefaultAccountState<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> DefaultAccountState<'info> {
@@ -48,9 +80,16 @@ impl<'info> DefaultAccountState<'info> {
 pub(crate) fn handler_for_default_account_state(
     ctx: Context<DefaultAccountState>,
     data_len: u64,
+    state: DefaultState,
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_mint_len(
+        &all.mint.to_account_info(),
+        data_len,
+        &[ExtensionType::DefaultAccountState],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -62,11 +101,8 @@ pub(crate) fn handler_for_default_account_state(
         &[all.payer.to_account_info(), all.mint.to_account_info()],
     )?;
 
-    let ix = initialize_default_account_state(
-        all.token_2022_program.key,
-        all.mint.key,
-        &AccountState::Frozen,
-    )?;
+    let ix =
+        initialize_default_account_state(all.token_2022_program.key, all.mint.key, &state.into())?;
 
     invoke(
         &ix,
@@ -83,6 +119,8 @@ pub(crate) fn handler_for_default_account_state(
         Some(all.all_mint_role.key),
     )?;
 
+    emit_mint_created(&all.mint)?;
+
     Ok(())
 }
 
@@ -95,33 +133,49 @@ pub struct UpdateDefaultAccountState<'info> {
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     pub token_2022_program: Program<'info, Token2022>,
-    pub freeze_auth: SystemAccount<'info>,
+    /// CHECK: Freeze authority, either a signer or a multisig whose signers are passed as remaining accounts
+    pub freeze_auth: UncheckedAccount<'info>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
-pub(crate) fn handler_for_update_default_account_state(
-    ctx: Context<UpdateDefaultAccountState>,
-    account_state: u8,
+pub(crate) fn handler_for_update_default_account_state<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateDefaultAccountState<'info>>,
+    account_state: DefaultState,
 ) -> Result<()> {
     let all = ctx.accounts;
 
-    let account_state = AccountState::try_from(account_state)
-        .map_err(|_| error!(ErrorCode::InvalidAccountState))?;
+    check_mint_extension::<DefaultAccountStateExtension>(&all.mint.to_account_info())?;
+
+    let signers = authority_signers(&all.freeze_auth, ctx.remaining_accounts)?;
+    let signer_keys: Vec<&Pubkey> = signers.iter().map(|signer| signer.key).collect();
 
     let ix = update_default_account_state(
         all.token_2022_program.key,
         &all.mint.key(),
         all.freeze_auth.key,
-        &[],
-        &account_state,
+        &signer_keys,
+        &account_state.into(),
     )?;
 
-    invoke(
-        &ix,
-        &[
-            all.token_2022_program.to_account_info(),
-            all.mint.to_account_info(),
-            all.freeze_auth.to_account_info(),
-        ],
-    )?;
+    let mut infos = vec![
+        all.token_2022_program.to_account_info(),
+        all.mint.to_account_info(),
+        all.freeze_auth.to_account_info(),
+    ];
+    infos.extend(signers.iter().cloned());
+
+    invoke(&ix, &infos)?;
+
+    emit!(DefaultStateChanged {
+        mint: all.mint.key(),
+        state: account_state,
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
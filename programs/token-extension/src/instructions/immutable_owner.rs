@@ -1,13 +1,20 @@
 use {
-    crate::error::ErrorCode,
+    crate::{
+        checks::{check_account_len, check_mint_len},
+        constants::PROGRAM_CONFIG_SEED,
+        error::ErrorCode,
+        state::ProgramConfig,
+        utils::{emit_mint_created, emit_token_account_created},
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{program::invoke, system_instruction},
     },
     anchor_spl::{
         token_2022::{
-            initialize_account3, initialize_immutable_owner, initialize_mint2, InitializeAccount3,
-            InitializeImmutableOwner, InitializeMint2,
+            initialize_account3, initialize_immutable_owner, initialize_mint2,
+            spl_token_2022::extension::ExtensionType, InitializeAccount3, InitializeImmutableOwner,
+            InitializeMint2,
         },
         token_interface::Token2022,
     },
@@ -33,6 +40,12 @@ pub struct ImmutableOwner<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> ImmutableOwner<'info> {
@@ -71,6 +84,8 @@ impl<'info> ImmutableOwner<'info> {
 pub(crate) fn handler(ctx: Context<ImmutableOwner>, mint_len: u64, account_len: u64) -> Result<()> {
     let all = ctx.accounts;
 
+    check_mint_len(&all.mint.to_account_info(), mint_len, &[])?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -84,6 +99,13 @@ pub(crate) fn handler(ctx: Context<ImmutableOwner>, mint_len: u64, account_len:
 
     initialize_mint2(all.initialize_mint_2_cpi(), 2, all.all_mint_role.key, None)?;
 
+    check_account_len(
+        &all.mint.to_account_info(),
+        &all.account.to_account_info(),
+        account_len,
+        &[ExtensionType::ImmutableOwner],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -97,5 +119,9 @@ pub(crate) fn handler(ctx: Context<ImmutableOwner>, mint_len: u64, account_len:
 
     initialize_immutable_owner(all.initialize_immutable_owner_cpi())?;
     initialize_account3(all.initialize_account3_cpi())?;
+
+    emit_mint_created(&all.mint)?;
+    emit_token_account_created(&all.account)?;
+
     Ok(())
 }
@@ -1,5 +1,12 @@
 use {
-    crate::error::ErrorCode,
+    crate::{
+        checks::{check_mint_extension, check_mint_len},
+        constants::{ONE_IN_BASIS_POINTS, PROGRAM_CONFIG_SEED, SECONDS_PER_YEAR},
+        error::ErrorCode,
+        events::InterestRateUpdated,
+        state::ProgramConfig,
+        utils::emit_mint_created,
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{program::invoke, system_instruction},
@@ -7,8 +14,17 @@ use {
     anchor_spl::{
         token_2022::{
             amount_to_ui_amount, initialize_mint2,
-            spl_token_2022::extension::interest_bearing_mint::instruction::initialize,
-            AmountToUiAmount, InitializeMint2,
+            spl_token_2022::{
+                extension::{
+                    interest_bearing_mint::{
+                        instruction::{initialize, update_rate},
+                        InterestBearingConfig,
+                    },
+                    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+                },
+                state::Mint as MintState,
+            },
+            ui_amount_to_amount, AmountToUiAmount, InitializeMint2, UiAmountToAmount,
         },
         token_interface::{Mint, Token2022},
     },
@@ -28,6 +44,12 @@ pub struct InterestBearingToken<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> InterestBearingToken<'info> {
@@ -47,6 +69,12 @@ pub(crate) fn handler_to_interest_bearing_token(
     rate: i16,
 ) -> Result<()> {
     let all = ctx.accounts;
+    check_mint_len(
+        &all.mint.to_account_info(),
+        mint_len,
+        &[ExtensionType::InterestBearingConfig],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -75,6 +103,8 @@ pub(crate) fn handler_to_interest_bearing_token(
 
     initialize_mint2(all.initialize_mint_2_cpi(), 2, all.all_mint_role.key, None)?;
 
+    emit_mint_created(&all.mint)?;
+
     Ok(())
 }
 
@@ -102,3 +132,158 @@ pub(crate) fn handle_amount_to_ui(ctx: Context<AmountToUI>, amount: u64) -> Resu
     let all = ctx.accounts;
     amount_to_ui_amount(all.amount_to_ui_amount_cpi(), amount)
 }
+
+#[derive(Accounts)]
+pub struct UpdateRate<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub rate_authority: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_update_rate(ctx: Context<UpdateRate>, rate: i16) -> Result<()> {
+    let all = ctx.accounts;
+
+    check_mint_extension::<InterestBearingConfig>(&all.mint.to_account_info())?;
+
+    let ix = update_rate(
+        all.token_2022_program.key,
+        &all.mint.key(),
+        all.rate_authority.key, // gets validated here
+        &[],
+        rate,
+    )?;
+
+    invoke(
+        &ix,
+        &[
+            all.token_2022_program.to_account_info(),
+            all.mint.to_account_info(),
+            all.rate_authority.to_account_info(),
+        ],
+    )?;
+
+    emit!(InterestRateUpdated {
+        mint: all.mint.key(),
+        rate,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UIToAmount<'info> {
+    #[account(
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+impl<'info> UIToAmount<'info> {
+    fn ui_amount_to_amount_cpi(&self) -> CpiContext<'_, '_, '_, 'info, UiAmountToAmount<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            UiAmountToAmount {
+                account: self.mint.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handle_ui_to_amount(ctx: Context<UIToAmount>, ui_amount: String) -> Result<u64> {
+    let all = ctx.accounts;
+
+    require!(!ui_amount.is_empty(), ErrorCode::InvalidAmount);
+
+    ui_amount_to_amount(all.ui_amount_to_amount_cpi(), &ui_amount)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InterestProjection {
+    /// Raw amount the projection was computed for
+    pub amount: u64,
+    /// Raw amount including the interest accrued up to `unix_timestamp`
+    pub accrued_amount: u64,
+    /// Timestamp the projection was computed at
+    pub unix_timestamp: i64,
+    /// Rate currently applied by the mint, in basis points
+    pub effective_rate: i16,
+    /// Average rate before the last rate update, in basis points
+    pub pre_update_average_rate: i16,
+    /// Time weighted average rate since initialization up to `unix_timestamp`, in basis points
+    pub current_average_rate: i16,
+}
+
+#[derive(Accounts)]
+pub struct ProjectInterest<'info> {
+    #[account(
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+pub(crate) fn handle_project_interest(
+    ctx: Context<ProjectInterest>,
+    amount: u64,
+    unix_timestamp: Option<i64>,
+) -> Result<InterestProjection> {
+    let all = ctx.accounts;
+
+    let now = Clock::get()?.unix_timestamp;
+    let unix_timestamp = unix_timestamp.unwrap_or(now);
+    require!(unix_timestamp >= now, ErrorCode::InvalidTimestamp);
+
+    let mint_info = all.mint.to_account_info();
+    check_mint_extension::<InterestBearingConfig>(&mint_info)?;
+
+    let mint_data = mint_info.data.borrow();
+    let mint = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let config = mint.get_extension::<InterestBearingConfig>()?;
+
+    let accrued_amount = interest_scale(config, unix_timestamp)
+        .map(|scale| (amount as f64 * scale).round())
+        .filter(|accrued| accrued.is_finite() && *accrued >= 0. && *accrued <= u64::MAX as f64)
+        .map(|accrued| accrued as u64)
+        .ok_or(ErrorCode::InterestOverflow)?;
+
+    let current_average_rate = config
+        .time_weighted_average_rate(unix_timestamp)
+        .ok_or(ErrorCode::InterestOverflow)?;
+
+    Ok(InterestProjection {
+        amount,
+        accrued_amount,
+        unix_timestamp,
+        effective_rate: config.current_rate.into(),
+        pre_update_average_rate: config.pre_update_average_rate.into(),
+        current_average_rate,
+    })
+}
+
+// Token-2022 keeps its scale private, so this repeats its continuous compounding over the
+// average rate before the last update and the current rate since.
+fn interest_scale(config: &InterestBearingConfig, unix_timestamp: i64) -> Option<f64> {
+    let growth = |rate: i16, timespan: i64| {
+        (f64::from(rate) * timespan as f64 / SECONDS_PER_YEAR / ONE_IN_BASIS_POINTS).exp()
+    };
+
+    let pre_update_timespan = i64::from(config.last_update_timestamp)
+        .checked_sub(config.initialization_timestamp.into())?;
+    let post_update_timespan = unix_timestamp.checked_sub(config.last_update_timestamp.into())?;
+
+    Some(
+        growth(config.pre_update_average_rate.into(), pre_update_timespan)
+            * growth(config.current_rate.into(), post_update_timespan),
+    )
+}
@@ -0,0 +1,317 @@
+use {
+    crate::{
+        checks::check_mint_extension,
+        constants::{PROGRAM_CONFIG_SEED, RATE_GOVERNANCE_SEED},
+        error::ErrorCode,
+        events::{
+            InterestRateUpdated, RateGovernanceInitialized, RateProposalCancelled, RateProposed,
+        },
+        state::{ProgramConfig, RateGovernance},
+    },
+    anchor_lang::{prelude::*, solana_program::program::invoke_signed},
+    anchor_spl::{
+        token_2022::{
+            set_authority,
+            spl_token_2022::{
+                extension::{
+                    interest_bearing_mint::{instruction::update_rate, InterestBearingConfig},
+                    BaseStateWithExtensions, StateWithExtensions,
+                },
+                instruction::AuthorityType,
+                state::Mint as MintState,
+            },
+            SetAuthority,
+        },
+        token_interface::{Mint, Token2022},
+    },
+};
+
+#[derive(Accounts)]
+pub struct InitializeRateGovernance<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RateGovernance::INIT_SPACE,
+        seeds = [RATE_GOVERNANCE_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub rate_governance: Account<'info, RateGovernance>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rate_authority: Signer<'info>,
+    pub admin: SystemAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> InitializeRateGovernance<'info> {
+    fn set_authority_cpi(&self) -> CpiContext<'_, '_, '_, 'info, SetAuthority<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            SetAuthority {
+                current_authority: self.rate_authority.to_account_info(),
+                account_or_mint: self.mint.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler_to_initialize_rate_governance(
+    ctx: Context<InitializeRateGovernance>,
+    min_rate: i16,
+    max_rate: i16,
+    max_change_per_period: u16,
+    period: i64,
+    timelock: i64,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    require!(min_rate <= max_rate, ErrorCode::InvalidRateBounds);
+    require!(period > 0 && timelock >= 0, ErrorCode::InvalidRateBounds);
+
+    check_mint_extension::<InterestBearingConfig>(&all.mint.to_account_info())?;
+
+    // Every later proposal is bounded, so the rate the governance starts from must be too.
+    let current_rate = current_rate(&all.mint.to_account_info())?;
+    require!(
+        current_rate >= min_rate && current_rate <= max_rate,
+        ErrorCode::RateOutOfBounds
+    );
+
+    all.rate_governance.set_inner(RateGovernance {
+        mint: all.mint.key(),
+        admin: all.admin.key(),
+        min_rate,
+        max_rate,
+        max_change_per_period,
+        period,
+        timelock,
+        period_start: Clock::get()?.unix_timestamp,
+        period_change: 0,
+        pending_rate: None,
+        proposed_at: 0,
+        bump: ctx.bumps.rate_governance,
+    });
+
+    // Hand the interest rate authority over to the governance PDA.
+    set_authority(
+        all.set_authority_cpi(),
+        AuthorityType::InterestRate,
+        Some(all.rate_governance.key()),
+    )?;
+
+    emit!(RateGovernanceInitialized {
+        mint: all.mint.key(),
+        governance: all.rate_governance.key(),
+        min_rate,
+        max_rate,
+        timelock,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeRate<'info> {
+    #[account(
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [RATE_GOVERNANCE_SEED, mint.key().as_ref()],
+        bump = rate_governance.bump,
+        has_one = mint,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub rate_governance: Account<'info, RateGovernance>,
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_propose_rate(ctx: Context<ProposeRate>, rate: i16) -> Result<()> {
+    let all = ctx.accounts;
+    let now = Clock::get()?.unix_timestamp;
+
+    check_mint_extension::<InterestBearingConfig>(&all.mint.to_account_info())?;
+
+    let current_rate = current_rate(&all.mint.to_account_info())?;
+
+    let governance = &mut all.rate_governance;
+
+    require!(
+        rate >= governance.min_rate && rate <= governance.max_rate,
+        ErrorCode::RateOutOfBounds
+    );
+
+    // The rate authority is the governance PDA, so the current rate can't move before execution.
+    // Checked against the period of the earliest execution and checked again when executing.
+    let executable_at = now.saturating_add(governance.timelock);
+    let change = period_change_at(governance, executable_at)
+        .checked_add(rate.abs_diff(current_rate))
+        .ok_or(ErrorCode::RateChangeTooLarge)?;
+    require!(
+        change <= governance.max_change_per_period,
+        ErrorCode::RateChangeTooLarge
+    );
+
+    governance.pending_rate = Some(rate);
+    governance.proposed_at = now;
+
+    emit!(RateProposed {
+        mint: governance.mint,
+        rate,
+        executable_at: governance.proposed_at.saturating_add(governance.timelock),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+// Shares the proposal accounts, only the governance admin can withdraw a pending rate.
+pub(crate) fn handler_to_cancel_rate(ctx: Context<ProposeRate>) -> Result<()> {
+    let governance = &mut ctx.accounts.rate_governance;
+
+    let rate = governance.pending_rate.ok_or(ErrorCode::NoPendingRate)?;
+
+    governance.pending_rate = None;
+    governance.proposed_at = 0;
+
+    emit!(RateProposalCancelled {
+        mint: governance.mint,
+        rate,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteRate<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [RATE_GOVERNANCE_SEED, mint.key().as_ref()],
+        bump = rate_governance.bump,
+        has_one = mint
+    )]
+    pub rate_governance: Account<'info, RateGovernance>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_execute_rate(ctx: Context<ExecuteRate>) -> Result<()> {
+    let all = ctx.accounts;
+    let now = Clock::get()?.unix_timestamp;
+
+    check_mint_extension::<InterestBearingConfig>(&all.mint.to_account_info())?;
+
+    let rate = all
+        .rate_governance
+        .pending_rate
+        .ok_or(ErrorCode::NoPendingRate)?;
+
+    require!(
+        now >= all
+            .rate_governance
+            .proposed_at
+            .saturating_add(all.rate_governance.timelock),
+        ErrorCode::TimelockNotElapsed
+    );
+
+    let current_rate = current_rate(&all.mint.to_account_info())?;
+
+    let governance = &mut all.rate_governance;
+
+    if now >= governance.period_start.saturating_add(governance.period) {
+        governance.period_start = now;
+        governance.period_change = 0;
+    }
+
+    let change = governance
+        .period_change
+        .checked_add(rate.abs_diff(current_rate))
+        .ok_or(ErrorCode::RateChangeTooLarge)?;
+    require!(
+        change <= governance.max_change_per_period,
+        ErrorCode::RateChangeTooLarge
+    );
+
+    governance.period_change = change;
+    governance.pending_rate = None;
+
+    let ix = update_rate(
+        all.token_2022_program.key,
+        &all.mint.key(),
+        &all.rate_governance.key(),
+        &[],
+        rate,
+    )?;
+
+    let mint = all.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        RATE_GOVERNANCE_SEED,
+        mint.as_ref(),
+        &[all.rate_governance.bump],
+    ]];
+
+    invoke_signed(
+        &ix,
+        &[
+            all.token_2022_program.to_account_info(),
+            all.mint.to_account_info(),
+            all.rate_governance.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    emit!(InterestRateUpdated {
+        mint: all.mint.key(),
+        rate,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+fn current_rate(mint_info: &AccountInfo) -> Result<i16> {
+    let mint_data = mint_info.data.borrow();
+    let mint = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let config = mint.get_extension::<InterestBearingConfig>()?;
+    Ok(config.current_rate.into())
+}
+
+// Rate change already spent in the period `at` falls into, a new period starts from zero.
+fn period_change_at(governance: &RateGovernance, at: i64) -> u16 {
+    if at >= governance.period_start.saturating_add(governance.period) {
+        0
+    } else {
+        governance.period_change
+    }
+}
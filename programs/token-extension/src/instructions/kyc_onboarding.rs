@@ -0,0 +1,259 @@
+use {
+    crate::{
+        constants::{ACCESS_REQUEST_SEED, KYC_CONFIG_SEED, PROGRAM_CONFIG_SEED},
+        error::ErrorCode,
+        events::{AccessApproved, AccessRequested, AccessRevoked, KycInitialized},
+        state::{AccessRequest, AccessStatus, KycConfig, ProgramConfig},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token_2022::{
+            freeze_account, set_authority, spl_token_2022::instruction::AuthorityType,
+            thaw_account, FreezeAccount, SetAuthority, ThawAccount,
+        },
+        token_interface::{Mint, Token2022, TokenAccount},
+    },
+};
+
+#[derive(Accounts)]
+pub struct InitializeKyc<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id(),
+        mint::freeze_authority = freeze_authority
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + KycConfig::INIT_SPACE,
+        seeds = [KYC_CONFIG_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub kyc_config: Account<'info, KycConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub freeze_authority: Signer<'info>,
+    pub compliance_officer: SystemAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> InitializeKyc<'info> {
+    fn set_authority_cpi(&self) -> CpiContext<'_, '_, '_, 'info, SetAuthority<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            SetAuthority {
+                current_authority: self.freeze_authority.to_account_info(),
+                account_or_mint: self.mint.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler_to_initialize_kyc(ctx: Context<InitializeKyc>) -> Result<()> {
+    let all = ctx.accounts;
+
+    all.kyc_config.set_inner(KycConfig {
+        mint: all.mint.key(),
+        compliance_officer: all.compliance_officer.key(),
+        bump: ctx.bumps.kyc_config,
+    });
+
+    // The config PDA becomes the freeze authority so only this program can thaw accounts.
+    set_authority(
+        all.set_authority_cpi(),
+        AuthorityType::FreezeAccount,
+        Some(all.kyc_config.key()),
+    )?;
+
+    emit!(KycInitialized {
+        mint: all.mint.key(),
+        compliance_officer: all.compliance_officer.key(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestAccess<'info> {
+    #[account(
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        token::mint = mint,
+        token::authority = wallet,
+        token::token_program = Token2022::id()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + AccessRequest::INIT_SPACE,
+        seeds = [ACCESS_REQUEST_SEED, token_account.key().as_ref()],
+        bump
+    )]
+    pub access_request: Account<'info, AccessRequest>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_request_access(ctx: Context<RequestAccess>) -> Result<()> {
+    let all = ctx.accounts;
+    let clock = Clock::get()?;
+
+    all.access_request.set_inner(AccessRequest {
+        mint: all.mint.key(),
+        token_account: all.token_account.key(),
+        wallet: all.wallet.key(),
+        status: AccessStatus::Pending,
+        requested_at: clock.unix_timestamp,
+        updated_at: clock.unix_timestamp,
+        bump: ctx.bumps.access_request,
+    });
+
+    emit!(AccessRequested {
+        mint: all.mint.key(),
+        token_account: all.token_account.key(),
+        wallet: all.wallet.key(),
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReviewAccess<'info> {
+    #[account(
+        mint::token_program = Token2022::id(),
+        mint::freeze_authority = kyc_config
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [KYC_CONFIG_SEED, mint.key().as_ref()],
+        bump = kyc_config.bump,
+        has_one = compliance_officer @ ErrorCode::InvalidComplianceOfficer
+    )]
+    pub kyc_config: Account<'info, KycConfig>,
+    #[account(
+        mut,
+        seeds = [ACCESS_REQUEST_SEED, token_account.key().as_ref()],
+        bump = access_request.bump,
+        has_one = mint,
+        has_one = token_account
+    )]
+    pub access_request: Account<'info, AccessRequest>,
+    pub compliance_officer: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    // Revoking stays available while the program is paused, approve checks the pause itself.
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> ReviewAccess<'info> {
+    fn thaw_account_cpi(&self) -> CpiContext<'_, '_, '_, 'info, ThawAccount<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            ThawAccount {
+                account: self.token_account.to_account_info(),
+                mint: self.mint.to_account_info(),
+                authority: self.kyc_config.to_account_info(),
+            },
+        )
+    }
+
+    fn freeze_account_cpi(&self) -> CpiContext<'_, '_, '_, 'info, FreezeAccount<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            FreezeAccount {
+                account: self.token_account.to_account_info(),
+                mint: self.mint.to_account_info(),
+                authority: self.kyc_config.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler_to_approve_access(ctx: Context<ReviewAccess>) -> Result<()> {
+    let all = ctx.accounts;
+    let clock = Clock::get()?;
+
+    require!(!all.program_config.paused, ErrorCode::ProgramPaused);
+
+    require!(
+        all.access_request.status != AccessStatus::Approved,
+        ErrorCode::InvalidAccessStatus
+    );
+
+    let mint = all.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[KYC_CONFIG_SEED, mint.as_ref(), &[all.kyc_config.bump]]];
+
+    if all.token_account.is_frozen() {
+        thaw_account(all.thaw_account_cpi().with_signer(signer_seeds))?;
+    }
+
+    all.access_request.status = AccessStatus::Approved;
+    all.access_request.updated_at = clock.unix_timestamp;
+
+    emit!(AccessApproved {
+        mint,
+        token_account: all.token_account.key(),
+        compliance_officer: all.compliance_officer.key(),
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn handler_to_revoke_access(ctx: Context<ReviewAccess>) -> Result<()> {
+    let all = ctx.accounts;
+    let clock = Clock::get()?;
+
+    require!(
+        all.access_request.status == AccessStatus::Approved,
+        ErrorCode::InvalidAccessStatus
+    );
+
+    let mint = all.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[KYC_CONFIG_SEED, mint.as_ref(), &[all.kyc_config.bump]]];
+
+    if !all.token_account.is_frozen() {
+        freeze_account(all.freeze_account_cpi().with_signer(signer_seeds))?;
+    }
+
+    all.access_request.status = AccessStatus::Revoked;
+    all.access_request.updated_at = clock.unix_timestamp;
+
+    emit!(AccessRevoked {
+        mint,
+        token_account: all.token_account.key(),
+        compliance_officer: all.compliance_officer.key(),
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
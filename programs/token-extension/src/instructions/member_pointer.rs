@@ -1,5 +1,8 @@
 use {
-    crate::error::ErrorCode,
+    crate::{
+        constants::PROGRAM_CONFIG_SEED, error::ErrorCode, state::ProgramConfig,
+        utils::emit_mint_created,
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{program::invoke, system_instruction},
@@ -32,6 +35,12 @@ pub struct InitializeMemberPointer<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> InitializeMemberPointer<'info> {
@@ -113,5 +122,7 @@ pub(crate) fn handler_to_initialize_member_pointer(
     //     ],
     // )?;
 
+    emit_mint_created(&all.member)?;
+
     Ok(())
 }
@@ -1,5 +1,12 @@
 use {
-    crate::error::ErrorCode,
+    crate::{
+        checks::{check_account_extension, check_account_len, check_transfer},
+        constants::{MAX_MEMO_LEN, MAX_MEMO_REFERENCE_LEN, PROGRAM_CONFIG_SEED},
+        error::ErrorCode,
+        events::{MemoRequirementChanged, TransferExecuted},
+        state::ProgramConfig,
+        utils::{emit_token_account_created, expected_transfer_fee},
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{program::invoke, system_instruction},
@@ -8,7 +15,19 @@ use {
         memo::{build_memo, BuildMemo, Memo},
         token_2022::{initialize_account3, transfer_checked, InitializeAccount3, TransferChecked},
         token_interface::{
-            spl_token_2022::extension::memo_transfer::instruction::enable_required_transfer_memos,
+            spl_token_2022::{
+                extension::{
+                    cpi_guard::CpiGuard,
+                    memo_transfer::{
+                        instruction::{
+                            disable_required_transfer_memos, enable_required_transfer_memos,
+                        },
+                        MemoTransfer as MemoTransferExtension,
+                    },
+                    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+                },
+                state::Account as AccountState,
+            },
             Mint, Token2022, TokenAccount, TokenInterface,
         },
     },
@@ -32,6 +51,12 @@ pub struct EnableMemo<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> EnableMemo<'info> {
@@ -50,6 +75,13 @@ impl<'info> EnableMemo<'info> {
 pub(crate) fn handler_to_enable_memo(ctx: Context<EnableMemo>, account_len: u64) -> Result<()> {
     let all = ctx.accounts;
 
+    check_account_len(
+        &all.mint.to_account_info(),
+        &all.token_account.to_account_info(),
+        account_len,
+        &[ExtensionType::MemoTransfer],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -82,9 +114,140 @@ pub(crate) fn handler_to_enable_memo(ctx: Context<EnableMemo>, account_len: u64)
         ],
     )?;
 
+    emit_token_account_created(&all.token_account)?;
+
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct DisableMemo<'info> {
+    #[account(
+        mut,
+        token::authority = owner,
+        token::token_program = Token2022::id()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_disable_memo(ctx: Context<DisableMemo>) -> Result<()> {
+    let all = ctx.accounts;
+
+    check_account_extension::<MemoTransferExtension>(&all.token_account.to_account_info())?;
+
+    let ix = disable_required_transfer_memos(
+        all.token_2022_program.key,
+        &all.token_account.key(),
+        all.owner.key,
+        &[],
+    )?;
+
+    invoke(
+        &ix,
+        &[
+            all.token_account.to_account_info(),
+            all.owner.to_account_info(),
+        ],
+    )?;
+
+    emit!(MemoRequirementChanged {
+        mint: all.token_account.mint,
+        token_account: all.token_account.key(),
+        required: false,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TransferRequirements {
+    /// Incoming transfers must be preceded by a memo
+    pub memo_required: bool,
+    /// Owner actions through CPI are blocked
+    pub cpi_guard_enabled: bool,
+}
+
+#[derive(Accounts)]
+pub struct GetTransferRequirements<'info> {
+    #[account(
+        token::token_program = Token2022::id()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+pub(crate) fn handle_transfer_requirements(
+    ctx: Context<GetTransferRequirements>,
+) -> Result<TransferRequirements> {
+    let info = ctx.accounts.token_account.to_account_info();
+    let data = info.try_borrow_data()?;
+    let account = StateWithExtensions::<AccountState>::unpack(&data)?;
+
+    Ok(TransferRequirements {
+        memo_required: account
+            .get_extension::<MemoTransferExtension>()
+            .map(|memo| bool::from(memo.require_incoming_transfer_memos))
+            .unwrap_or(false),
+        cpi_guard_enabled: account
+            .get_extension::<CpiGuard>()
+            .map(|guard| bool::from(guard.lock_cpi))
+            .unwrap_or(false),
+    })
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum TransferMemo {
+    /// Free form memo text
+    Text { text: String },
+    /// Payment reference written as `{"invoice":"..","payer":".."}`
+    Reference {
+        invoice_id: String,
+        payer_reference: String,
+    },
+}
+
+impl TransferMemo {
+    fn check_reference(value: &str) -> Result<()> {
+        require!(
+            !value.is_empty()
+                && value.len() <= MAX_MEMO_REFERENCE_LEN
+                && value
+                    .bytes()
+                    .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_'),
+            ErrorCode::InvalidMemoReference
+        );
+        Ok(())
+    }
+
+    /// Validate the memo and render the bytes written by the memo program.
+    pub fn to_memo(&self) -> Result<String> {
+        let memo = match self {
+            TransferMemo::Text { text } => text.clone(),
+            TransferMemo::Reference {
+                invoice_id,
+                payer_reference,
+            } => {
+                Self::check_reference(invoice_id)?;
+                Self::check_reference(payer_reference)?;
+                format!(r#"{{"invoice":"{invoice_id}","payer":"{payer_reference}"}}"#)
+            }
+        };
+
+        require!(
+            !memo.is_empty() && memo.len() <= MAX_MEMO_LEN,
+            ErrorCode::InvalidMemo
+        );
+        Ok(memo)
+    }
+}
+
 #[derive(Accounts)]
 pub struct MemoTransfer<'info> {
     #[account(
@@ -107,6 +270,12 @@ pub struct MemoTransfer<'info> {
     pub token_2022_program: Interface<'info, TokenInterface>,
     pub authority: Signer<'info>,
     pub memo_program: Program<'info, Memo>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> MemoTransfer<'info> {
@@ -123,7 +292,7 @@ impl<'info> MemoTransfer<'info> {
     }
 
     fn build_memo_cpi(&self) -> CpiContext<'_, '_, '_, 'info, BuildMemo> {
-        CpiContext::new(self.token_2022_program.to_account_info(), BuildMemo {})
+        CpiContext::new(self.memo_program.to_account_info(), BuildMemo {})
     }
 }
 
@@ -131,14 +300,34 @@ pub(crate) fn handler_to_memo_transfer(
     ctx: Context<MemoTransfer>,
     amount: u64,
     decimals: u8,
+    memo: TransferMemo,
 ) -> Result<()> {
     let all = ctx.accounts;
 
     require!(amount > 0, ErrorCode::InvalidAmount);
+    check_transfer(
+        &all.mint.to_account_info(),
+        &all.from_acc.to_account_info(),
+        &all.to_acc.to_account_info(),
+        amount,
+        None,
+        true,
+    )?;
 
-    build_memo(all.build_memo_cpi(), "Testing memo string".as_bytes())?;
+    let memo = memo.to_memo()?;
+    build_memo(all.build_memo_cpi(), memo.as_bytes())?;
 
     transfer_checked(all.transfer_checked_cpi(), amount, decimals)?;
 
+    emit!(TransferExecuted {
+        mint: all.mint.key(),
+        from: all.from_acc.key(),
+        to: all.to_acc.key(),
+        amount,
+        fee: expected_transfer_fee(&all.mint.to_account_info(), amount)?,
+        memo: Some(memo),
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
@@ -6,7 +6,8 @@ use {
     anchor_spl::{
         token_2022::{initialize_mint2, InitializeMint2},
         token_interface::{
-            spl_token_2022::extension::metadata_pointer::instruction::initialize, Mint, Token2022,
+            spl_token_2022::extension::{metadata_pointer::instruction::initialize, ExtensionType},
+            Mint, Token2022,
         },
     },
     spl_token_metadata_interface::{
@@ -15,7 +16,7 @@ use {
     },
 };
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub enum Field {
     /// The name field, corresponding to `TokenMetadata.name`
     Name,
@@ -38,7 +39,10 @@ impl From<Field> for MetaField {
     }
 }
 
-use crate::error::ErrorCode;
+use crate::{
+    checks::check_mint_len, constants::PROGRAM_CONFIG_SEED, error::ErrorCode,
+    events::MetadataUpdated, state::ProgramConfig, utils::emit_mint_created,
+};
 
 #[derive(Accounts)]
 pub struct MetadataPointer<'info> {
@@ -54,6 +58,12 @@ pub struct MetadataPointer<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> MetadataPointer<'info> {
@@ -81,6 +91,12 @@ pub(crate) fn handler_to_initialize_metadata_pointer(
     require!(!symbol.is_empty(), ErrorCode::InvalidSymbol); // simple check
     require!(!uri.is_empty(), ErrorCode::InvalidUri); // simple check or can use url crate to perform all checks
 
+    check_mint_len(
+        &all.mint.to_account_info(),
+        mint_len,
+        &[ExtensionType::MetadataPointer],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -135,6 +151,8 @@ pub(crate) fn handler_to_initialize_metadata_pointer(
         ],
     )?;
 
+    emit_mint_created(&all.mint)?;
+
     Ok(())
 }
 
@@ -149,6 +167,12 @@ pub struct UpdateMetadataField<'info> {
     pub payer: Signer<'info>,
     pub update_authority: Signer<'info>,
     pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 pub(crate) fn handler_to_update_metadata_field(
@@ -162,8 +186,8 @@ pub(crate) fn handler_to_update_metadata_field(
         all.token_2022_program.key,
         &all.mint.key(),
         all.update_authority.key, // gets validated here
-        field.into(),
-        value,
+        field.clone().into(),
+        value.clone(),
     );
 
     invoke(
@@ -175,5 +199,12 @@ pub(crate) fn handler_to_update_metadata_field(
         ],
     )?;
 
+    emit!(MetadataUpdated {
+        mint: all.mint.key(),
+        field,
+        value,
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
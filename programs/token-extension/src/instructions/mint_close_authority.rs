@@ -1,12 +1,16 @@
 use {
-    crate::error::ErrorCode,
+    crate::{
+        checks::check_mint_len, constants::PROGRAM_CONFIG_SEED, error::ErrorCode,
+        state::ProgramConfig, utils::emit_mint_created,
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{program::invoke, system_instruction},
     },
     anchor_spl::token_2022::{
-        self, initialize_mint2, initialize_mint_close_authority, InitializeMint2,
-        InitializeMintCloseAuthority, Token2022,
+        self, initialize_mint2, initialize_mint_close_authority,
+        spl_token_2022::extension::ExtensionType, InitializeMint2, InitializeMintCloseAuthority,
+        Token2022,
     },
 };
 
@@ -24,6 +28,12 @@ pub struct MintCloseAuthority<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> MintCloseAuthority<'info> {
@@ -51,6 +61,12 @@ impl<'info> MintCloseAuthority<'info> {
 pub(crate) fn handler(ctx: Context<MintCloseAuthority>, data_len: u64) -> Result<()> {
     let all = ctx.accounts;
 
+    check_mint_len(
+        &all.mint.to_account_info(),
+        data_len,
+        &[ExtensionType::MintCloseAuthority],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -68,5 +84,8 @@ pub(crate) fn handler(ctx: Context<MintCloseAuthority>, data_len: u64) -> Result
     )?;
 
     initialize_mint2(all.initialize_mint_2_cpi(), 6, all.all_mint_role.key, None)?;
+
+    emit_mint_created(&all.mint)?;
+
     Ok(())
 }
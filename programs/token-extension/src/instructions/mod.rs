@@ -1,33 +1,53 @@
+pub mod authority;
+pub mod batch_freeze;
 pub mod close_mint_account;
+pub mod close_token_accounts;
 pub mod confidential_transfer;
+pub mod confidential_transfer_fee;
 pub mod cpi_guard;
+pub mod create_token_account;
 pub mod default_account_state;
 pub mod group_pointer;
 pub mod immutable_owner;
 pub mod interest_bearing_token;
+pub mod interest_rate_governance;
+pub mod kyc_onboarding;
 pub mod member_pointer;
 pub mod memo_transfer;
 pub mod metadata_pointer;
 pub mod mint_close_authority;
 pub mod non_transferable_tokens;
 pub mod permanent_delegate;
+pub mod permanent_delegate_governance;
+pub mod program_admin;
 pub mod realloc;
+pub mod soulbound_credential;
 pub mod transfer_fee;
 pub mod transfer_hook;
 
+pub use authority::*;
+pub use batch_freeze::*;
 pub use close_mint_account::*;
+pub use close_token_accounts::*;
 pub use confidential_transfer::*;
+pub use confidential_transfer_fee::*;
 pub use cpi_guard::*;
+pub use create_token_account::*;
 pub use default_account_state::*;
 pub use group_pointer::*;
 pub use immutable_owner::*;
 pub use interest_bearing_token::*;
+pub use interest_rate_governance::*;
+pub use kyc_onboarding::*;
 pub use member_pointer::*;
 pub use memo_transfer::*;
 pub use metadata_pointer::*;
 pub use mint_close_authority::*;
 pub use non_transferable_tokens::*;
 pub use permanent_delegate::*;
+pub use permanent_delegate_governance::*;
+pub use program_admin::*;
 pub use realloc::*;
+pub use soulbound_credential::*;
 pub use transfer_fee::*;
 pub use transfer_hook::*;
@@ -1,13 +1,20 @@
 use {
-    crate::error::ErrorCode,
+    crate::{
+        checks::{check_account_len, check_mint_len},
+        constants::PROGRAM_CONFIG_SEED,
+        error::ErrorCode,
+        state::ProgramConfig,
+        utils::{emit_mint_created, emit_token_account_created},
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{program::invoke, system_instruction},
     },
     anchor_spl::{
         token_2022::{
-            initialize_account3, initialize_immutable_owner, initialize_mint2, InitializeAccount3,
-            InitializeImmutableOwner, InitializeMint2,
+            initialize_account3, initialize_immutable_owner, initialize_mint2,
+            spl_token_2022::extension::ExtensionType, InitializeAccount3, InitializeImmutableOwner,
+            InitializeMint2,
         },
         token_interface::{
             spl_token_2022::instruction::initialize_non_transferable_mint, Token2022,
@@ -35,6 +42,12 @@ pub struct NonTransferableToken<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> NonTransferableToken<'info> {
@@ -77,6 +90,12 @@ pub(crate) fn handler(
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_mint_len(
+        &all.mint.to_account_info(),
+        mint_len,
+        &[ExtensionType::NonTransferable],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -104,6 +123,13 @@ pub(crate) fn handler(
 
     initialize_mint2(all.initialize_mint_2_cpi(), 2, all.all_mint_role.key, None)?;
 
+    check_account_len(
+        &all.mint.to_account_info(),
+        &all.account.to_account_info(),
+        account_len,
+        &[ExtensionType::ImmutableOwner],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -122,5 +148,9 @@ pub(crate) fn handler(
     initialize_immutable_owner(all.initialize_immutable_owner_cpi())?;
 
     initialize_account3(all.initialize_account3_cpi())?;
+
+    emit_mint_created(&all.mint)?;
+    emit_token_account_created(&all.account)?;
+
     Ok(())
 }
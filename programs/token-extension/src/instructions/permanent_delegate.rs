@@ -1,5 +1,12 @@
 use {
-    crate::error::ErrorCode,
+    crate::{
+        checks::{check_mint_extension, check_mint_len, check_not_frozen, check_transfer},
+        constants::{MAX_MEMO_LEN, PROGRAM_CONFIG_SEED},
+        error::ErrorCode,
+        events::{DelegateBurned, TokensClawedBack},
+        state::ProgramConfig,
+        utils::{emit_mint_created, emit_token_account_created},
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{program::invoke, system_instruction},
@@ -9,10 +16,21 @@ use {
             create_idempotent, get_associated_token_address_with_program_id, AssociatedToken,
             Create,
         },
-        token_2022::{burn, initialize_mint2, Burn, InitializeMint2, Token2022},
+        memo::{build_memo, BuildMemo, Memo},
+        token_2022::{
+            burn, initialize_mint2, transfer_checked, Burn, InitializeMint2, Token2022,
+            TransferChecked,
+        },
         token_interface::{
-            spl_token_2022::instruction::initialize_permanent_delegate, Mint, TokenAccount,
-            TokenInterface,
+            spl_token_2022::{
+                extension::{
+                    permanent_delegate::PermanentDelegate as PermanentDelegateExtension,
+                    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+                },
+                instruction::initialize_permanent_delegate,
+                state::Mint as MintState,
+            },
+            Mint, TokenAccount, TokenInterface,
         },
     },
 };
@@ -31,6 +49,12 @@ pub struct PermanentDelegate<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> PermanentDelegate<'info> {
@@ -50,6 +74,12 @@ pub(crate) fn handler_to_permanent_delegate(
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_mint_len(
+        &all.mint.to_account_info(),
+        mint_len,
+        &[ExtensionType::PermanentDelegate],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -76,7 +106,11 @@ pub(crate) fn handler_to_permanent_delegate(
         ],
     )?;
 
-    initialize_mint2(all.initialize_mint_2_cpi(), 0, all.all_mint_role.key, None)
+    initialize_mint2(all.initialize_mint_2_cpi(), 0, all.all_mint_role.key, None)?;
+
+    emit_mint_created(&all.mint)?;
+
+    Ok(())
 }
 
 #[derive(Accounts)]
@@ -99,6 +133,12 @@ pub struct CreateATA<'info> {
     pub rent: Sysvar<'info, Rent>,
     pub token_2022_program: Program<'info, Token2022>,
     pub associated_program: Program<'info, AssociatedToken>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> CreateATA<'info> {
@@ -120,7 +160,14 @@ impl<'info> CreateATA<'info> {
 pub(crate) fn handler_to_create_ata(ctx: Context<CreateATA>) -> Result<()> {
     let all = ctx.accounts;
 
-    create_idempotent(all.create_ata_cpi())
+    let created = all.associated_token.data_is_empty();
+    create_idempotent(all.create_ata_cpi())?;
+
+    if created {
+        emit_token_account_created(&all.associated_token)?;
+    }
+
+    Ok(())
 }
 
 #[derive(Accounts)]
@@ -130,10 +177,20 @@ pub struct BurnCpi<'info> {
         mint::token_program = Token2022::id()
     )]
     pub mint: InterfaceAccount<'info, Mint>,
-    #[account(mut)]
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
     pub from: InterfaceAccount<'info, TokenAccount>,
     pub token_2022_program: Interface<'info, TokenInterface>,
     pub delegate: Signer<'info>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> BurnCpi<'info> {
@@ -152,5 +209,122 @@ impl<'info> BurnCpi<'info> {
 pub(crate) fn handler_to_burn(ctx: Context<BurnCpi>, amount: u64) -> Result<()> {
     let all = ctx.accounts;
 
-    burn(all.burn_cpi(), amount)
+    check_permanent_delegate(&all.mint.to_account_info(), all.delegate.key)?;
+    check_not_frozen(&all.from.to_account_info())?;
+
+    burn(all.burn_cpi(), amount)?;
+
+    emit!(DelegateBurned {
+        mint: all.mint.key(),
+        from: all.from.key(),
+        delegate: all.delegate.key(),
+        amount,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Clawback<'info> {
+    #[account(
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id(),
+        constraint = treasury.key() != from.key() @ ErrorCode::InvalidTreasury
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+    pub delegate: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub memo_program: Program<'info, Memo>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> Clawback<'info> {
+    fn transfer_checked_cpi(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            TransferChecked {
+                from: self.from.to_account_info(),
+                mint: self.mint.to_account_info(),
+                to: self.treasury.to_account_info(),
+                authority: self.delegate.to_account_info(),
+            },
+        )
+    }
+
+    fn build_memo_cpi(&self) -> CpiContext<'_, '_, '_, 'info, BuildMemo> {
+        CpiContext::new(self.memo_program.to_account_info(), BuildMemo {})
+    }
+}
+
+pub(crate) fn handler_to_clawback(
+    ctx: Context<Clawback>,
+    amount: u64,
+    reason: String,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    require!(
+        !reason.is_empty() && reason.len() <= MAX_MEMO_LEN,
+        ErrorCode::InvalidMemo
+    );
+
+    check_permanent_delegate(&all.mint.to_account_info(), all.delegate.key)?;
+    check_transfer(
+        &all.mint.to_account_info(),
+        &all.from.to_account_info(),
+        &all.treasury.to_account_info(),
+        amount,
+        None,
+        true,
+    )?;
+
+    // Memo goes first so it also satisfies a treasury that requires incoming memos.
+    build_memo(all.build_memo_cpi(), reason.as_bytes())?;
+
+    transfer_checked(all.transfer_checked_cpi(), amount, all.mint.decimals)?;
+
+    emit!(TokensClawedBack {
+        mint: all.mint.key(),
+        from: all.from.key(),
+        treasury: all.treasury.key(),
+        delegate: all.delegate.key(),
+        amount,
+        reason,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn check_permanent_delegate(mint_info: &AccountInfo, delegate: &Pubkey) -> Result<()> {
+    check_mint_extension::<PermanentDelegateExtension>(mint_info)?;
+
+    let mint_data = mint_info.data.borrow();
+    let mint = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let extension = mint.get_extension::<PermanentDelegateExtension>()?;
+
+    require!(
+        Option::<Pubkey>::from(extension.delegate) == Some(*delegate),
+        ErrorCode::InvalidPermanentDelegate
+    );
+
+    Ok(())
 }
@@ -0,0 +1,466 @@
+use {
+    crate::{
+        checks::{check_not_frozen, check_transfer},
+        constants::{
+            DELEGATE_GOVERNANCE_SEED, DELEGATE_PROPOSAL_SEED, MAX_DELEGATE_SIGNERS, MAX_MEMO_LEN,
+            PROGRAM_CONFIG_SEED,
+        },
+        error::ErrorCode,
+        events::{
+            DelegateBurned, DelegateGovernanceInitialized, DelegateProposalApproved,
+            DelegateProposalCancelled, DelegateProposalCreated, DelegateProposalExecuted,
+            TokensClawedBack,
+        },
+        state::{
+            DelegateAction, DelegateGovernance, DelegateProposal, ProgramConfig, ProposalStatus,
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        memo::{build_memo, BuildMemo, Memo},
+        token_2022::{
+            burn, set_authority, spl_token_2022::instruction::AuthorityType, transfer_checked,
+            Burn, SetAuthority, TransferChecked,
+        },
+        token_interface::{Mint, Token2022, TokenAccount},
+    },
+};
+
+#[derive(Accounts)]
+pub struct InitializeDelegateGovernance<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + DelegateGovernance::INIT_SPACE,
+        seeds = [DELEGATE_GOVERNANCE_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub delegate_governance: Account<'info, DelegateGovernance>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub permanent_delegate: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> InitializeDelegateGovernance<'info> {
+    fn set_authority_cpi(&self) -> CpiContext<'_, '_, '_, 'info, SetAuthority<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            SetAuthority {
+                current_authority: self.permanent_delegate.to_account_info(),
+                account_or_mint: self.mint.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler_to_initialize_delegate_governance(
+    ctx: Context<InitializeDelegateGovernance>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_DELEGATE_SIGNERS,
+        ErrorCode::InvalidThreshold
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        ErrorCode::InvalidThreshold
+    );
+    for (index, signer) in signers.iter().enumerate() {
+        require!(
+            !signers[..index].contains(signer),
+            ErrorCode::InvalidThreshold
+        );
+    }
+
+    all.delegate_governance.set_inner(DelegateGovernance {
+        mint: all.mint.key(),
+        signers,
+        threshold,
+        proposal_count: 0,
+        bump: ctx.bumps.delegate_governance,
+    });
+
+    // Hand the permanent delegate over to the governance PDA.
+    set_authority(
+        all.set_authority_cpi(),
+        AuthorityType::PermanentDelegate,
+        Some(all.delegate_governance.key()),
+    )?;
+
+    emit!(DelegateGovernanceInitialized {
+        mint: all.mint.key(),
+        governance: all.delegate_governance.key(),
+        signers: all.delegate_governance.signers.clone(),
+        threshold,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeDelegateAction<'info> {
+    #[account(
+        mut,
+        seeds = [DELEGATE_GOVERNANCE_SEED, delegate_governance.mint.as_ref()],
+        bump = delegate_governance.bump
+    )]
+    pub delegate_governance: Account<'info, DelegateGovernance>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + DelegateProposal::INIT_SPACE,
+        seeds = [
+            DELEGATE_PROPOSAL_SEED,
+            delegate_governance.key().as_ref(),
+            &delegate_governance.proposal_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, DelegateProposal>,
+    #[account(
+        token::mint = delegate_governance.mint,
+        token::token_program = Token2022::id()
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        token::mint = delegate_governance.mint,
+        token::token_program = Token2022::id()
+    )]
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_propose_delegate_action(
+    ctx: Context<ProposeDelegateAction>,
+    action: DelegateAction,
+    amount: u64,
+    reason: String,
+    ttl: i64,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    require!(
+        !reason.is_empty() && reason.len() <= MAX_MEMO_LEN,
+        ErrorCode::InvalidMemo
+    );
+    require!(ttl > 0, ErrorCode::InvalidTimestamp);
+    require!(
+        all.delegate_governance.signers.contains(all.proposer.key),
+        ErrorCode::NotGovernanceSigner
+    );
+
+    let treasury = all.treasury.as_ref().map(|treasury| treasury.key());
+    match action {
+        DelegateAction::Burn => require!(treasury.is_none(), ErrorCode::InvalidTreasury),
+        DelegateAction::Clawback => require!(
+            treasury.is_some_and(|treasury| treasury != all.from.key()),
+            ErrorCode::InvalidTreasury
+        ),
+    }
+
+    let id = all.delegate_governance.proposal_count;
+    all.delegate_governance.proposal_count += 1;
+
+    all.proposal.set_inner(DelegateProposal {
+        governance: all.delegate_governance.key(),
+        id,
+        action,
+        from: all.from.key(),
+        treasury,
+        amount,
+        reason,
+        proposer: all.proposer.key(),
+        // The proposer approves its own proposal.
+        approvals: vec![all.proposer.key()],
+        expires_at: Clock::get()?.unix_timestamp.saturating_add(ttl),
+        status: ProposalStatus::Active,
+        bump: ctx.bumps.proposal,
+    });
+
+    emit!(DelegateProposalCreated {
+        mint: all.delegate_governance.mint,
+        proposal: all.proposal.key(),
+        proposer: all.proposer.key(),
+        action,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReviewDelegateProposal<'info> {
+    #[account(
+        seeds = [DELEGATE_GOVERNANCE_SEED, delegate_governance.mint.as_ref()],
+        bump = delegate_governance.bump
+    )]
+    pub delegate_governance: Account<'info, DelegateGovernance>,
+    #[account(
+        mut,
+        seeds = [
+            DELEGATE_PROPOSAL_SEED,
+            delegate_governance.key().as_ref(),
+            &proposal.id.to_le_bytes()
+        ],
+        bump = proposal.bump,
+        constraint = proposal.governance == delegate_governance.key() @ ErrorCode::ProposalAccountMismatch
+    )]
+    pub proposal: Account<'info, DelegateProposal>,
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_approve_delegate_proposal(
+    ctx: Context<ReviewDelegateProposal>,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    require!(
+        all.delegate_governance.signers.contains(all.signer.key),
+        ErrorCode::NotGovernanceSigner
+    );
+    all.proposal.check_active()?;
+    require!(
+        !all.proposal.approvals.contains(all.signer.key),
+        ErrorCode::AlreadyApproved
+    );
+
+    all.proposal.approvals.push(all.signer.key());
+
+    emit!(DelegateProposalApproved {
+        mint: all.delegate_governance.mint,
+        proposal: all.proposal.key(),
+        signer: all.signer.key(),
+        approvals: all.proposal.approvals.len() as u8,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+// Only the proposer can withdraw a proposal, other signers decline by not approving it.
+pub(crate) fn handler_to_cancel_delegate_proposal(
+    ctx: Context<ReviewDelegateProposal>,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    require!(
+        all.delegate_governance.signers.contains(all.signer.key),
+        ErrorCode::NotGovernanceSigner
+    );
+    require_keys_eq!(
+        all.proposal.proposer,
+        all.signer.key(),
+        ErrorCode::Unauthorized
+    );
+    require!(
+        all.proposal.status == ProposalStatus::Active,
+        ErrorCode::ProposalNotActive
+    );
+
+    all.proposal.status = ProposalStatus::Cancelled;
+
+    emit!(DelegateProposalCancelled {
+        mint: all.delegate_governance.mint,
+        proposal: all.proposal.key(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteDelegateProposal<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds = [DELEGATE_GOVERNANCE_SEED, mint.key().as_ref()],
+        bump = delegate_governance.bump,
+        has_one = mint
+    )]
+    pub delegate_governance: Account<'info, DelegateGovernance>,
+    #[account(
+        mut,
+        seeds = [
+            DELEGATE_PROPOSAL_SEED,
+            delegate_governance.key().as_ref(),
+            &proposal.id.to_le_bytes()
+        ],
+        bump = proposal.bump,
+        constraint = proposal.governance == delegate_governance.key() @ ErrorCode::ProposalAccountMismatch,
+        constraint = proposal.from == from.key() @ ErrorCode::ProposalAccountMismatch,
+        constraint = proposal.treasury == treasury.as_ref().map(|treasury| treasury.key()) @ ErrorCode::ProposalAccountMismatch
+    )]
+    pub proposal: Account<'info, DelegateProposal>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub memo_program: Program<'info, Memo>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> ExecuteDelegateProposal<'info> {
+    fn build_memo_cpi(&self) -> CpiContext<'_, '_, '_, 'info, BuildMemo> {
+        CpiContext::new(self.memo_program.to_account_info(), BuildMemo {})
+    }
+}
+
+pub(crate) fn handler_to_execute_delegate_proposal(
+    ctx: Context<ExecuteDelegateProposal>,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    all.proposal.check_active()?;
+    require!(
+        all.proposal.approvals.len() >= all.delegate_governance.threshold as usize,
+        ErrorCode::NotEnoughApprovals
+    );
+
+    let mint = all.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        DELEGATE_GOVERNANCE_SEED,
+        mint.as_ref(),
+        &[all.delegate_governance.bump],
+    ]];
+
+    match (all.proposal.action, all.treasury.as_ref()) {
+        (DelegateAction::Burn, _) => {
+            check_not_frozen(&all.from.to_account_info())?;
+
+            burn(
+                CpiContext::new_with_signer(
+                    all.token_2022_program.to_account_info(),
+                    Burn {
+                        mint: all.mint.to_account_info(),
+                        from: all.from.to_account_info(),
+                        authority: all.delegate_governance.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                all.proposal.amount,
+            )?
+        }
+        (DelegateAction::Clawback, Some(treasury)) => {
+            check_transfer(
+                &all.mint.to_account_info(),
+                &all.from.to_account_info(),
+                &treasury.to_account_info(),
+                all.proposal.amount,
+                None,
+                true,
+            )?;
+
+            // Memo goes first so it also satisfies a treasury that requires incoming memos.
+            build_memo(all.build_memo_cpi(), all.proposal.reason.as_bytes())?;
+
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    all.token_2022_program.to_account_info(),
+                    TransferChecked {
+                        from: all.from.to_account_info(),
+                        mint: all.mint.to_account_info(),
+                        to: treasury.to_account_info(),
+                        authority: all.delegate_governance.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                all.proposal.amount,
+                all.mint.decimals,
+            )?
+        }
+        (DelegateAction::Clawback, None) => return err!(ErrorCode::InvalidTreasury),
+    }
+
+    all.proposal.status = ProposalStatus::Executed;
+
+    let slot = Clock::get()?.slot;
+    match all.proposal.action {
+        DelegateAction::Burn => emit!(DelegateBurned {
+            mint,
+            from: all.from.key(),
+            delegate: all.delegate_governance.key(),
+            amount: all.proposal.amount,
+            slot,
+        }),
+        DelegateAction::Clawback => emit!(TokensClawedBack {
+            mint,
+            from: all.from.key(),
+            treasury: all.proposal.treasury.unwrap_or_default(),
+            delegate: all.delegate_governance.key(),
+            amount: all.proposal.amount,
+            reason: all.proposal.reason.clone(),
+            slot,
+        }),
+    }
+    emit!(DelegateProposalExecuted {
+        mint,
+        proposal: all.proposal.key(),
+        action: all.proposal.action,
+        slot,
+    });
+
+    Ok(())
+}
+
+impl DelegateProposal {
+    fn check_active(&self) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Active,
+            ErrorCode::ProposalNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp < self.expires_at,
+            ErrorCode::ProposalExpired
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use {
+    crate::{
+        constants::PROGRAM_CONFIG_SEED,
+        error::ErrorCode,
+        events::{AdminProposed, AdminTransferred, PauseUpdated, ProgramConfigInitialized},
+        program::TokenExtension,
+        state::ProgramConfig,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct InitializeProgramConfig<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProgramConfig::INIT_SPACE,
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+    )]
+    pub program: Program<'info, TokenExtension>,
+    // Only the upgrade authority can claim the admin role.
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::InvalidAdmin
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub admin: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler_to_initialize_program_config(
+    ctx: Context<InitializeProgramConfig>,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    all.program_config.set_inner(ProgramConfig {
+        admin: all.admin.key(),
+        pending_admin: None,
+        paused: false,
+        bump: ctx.bumps.program_config,
+    });
+
+    emit!(ProgramConfigInitialized {
+        admin: all.admin.key(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateProgramConfig<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        has_one = admin @ ErrorCode::InvalidAdmin
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    pub admin: Signer<'info>,
+}
+
+pub(crate) fn handler_to_propose_admin(
+    ctx: Context<UpdateProgramConfig>,
+    new_admin: Option<Pubkey>,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    all.program_config.pending_admin = new_admin;
+
+    emit!(AdminProposed {
+        admin: all.admin.key(),
+        pending_admin: new_admin,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn handler_to_set_paused(ctx: Context<UpdateProgramConfig>, paused: bool) -> Result<()> {
+    let all = ctx.accounts;
+
+    all.program_config.paused = paused;
+
+    emit!(PauseUpdated {
+        admin: all.admin.key(),
+        paused,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = program_config.pending_admin == Some(pending_admin.key()) @ ErrorCode::InvalidPendingAdmin
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    pub pending_admin: Signer<'info>,
+}
+
+pub(crate) fn handler_to_accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let all = ctx.accounts;
+
+    let previous_admin = all.program_config.admin;
+    all.program_config.admin = all.pending_admin.key();
+    all.program_config.pending_admin = None;
+
+    emit!(AdminTransferred {
+        previous_admin,
+        admin: all.pending_admin.key(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
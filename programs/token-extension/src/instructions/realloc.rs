@@ -1,10 +1,23 @@
 use {
-    anchor_lang::{prelude::*, solana_program::program::invoke},
+    crate::{
+        constants::PROGRAM_CONFIG_SEED,
+        error::ErrorCode,
+        events::{AccountReallocated, CpiGuardToggleRequested, MemoRequirementChanged},
+        state::ProgramConfig,
+        utils::check_next_cpi_guard_toggle,
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::program::invoke,
+        system_program::{transfer, Transfer},
+    },
     anchor_spl::token_interface::{
         spl_token_2022::extension::{
-            memo_transfer::instruction::enable_required_transfer_memos, ExtensionType,
+            cpi_guard::instruction::enable_cpi_guard,
+            memo_transfer::instruction::enable_required_transfer_memos, BaseStateWithExtensions,
+            ExtensionType, StateWithExtensions,
         },
-        spl_token_2022::instruction::reallocate,
+        spl_token_2022::{instruction::reallocate, state::Account as AccountState},
         Token2022, TokenAccount,
     },
 };
@@ -18,6 +31,12 @@ pub struct Realloc<'info> {
     pub all_mint_role: Signer<'info>,
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 pub(crate) fn handler_to_realloc(ctx: Context<Realloc>) -> Result<()> {
@@ -56,5 +75,189 @@ pub(crate) fn handler_to_realloc(ctx: Context<Realloc>) -> Result<()> {
         ],
     )?;
 
+    let slot = Clock::get()?.slot;
+    emit!(AccountReallocated {
+        mint: all.token_account.mint,
+        token_account: all.token_account.key(),
+        extensions: vec![ExtensionType::MemoTransfer.into()],
+        slot,
+    });
+    emit!(MemoRequirementChanged {
+        mint: all.token_account.mint,
+        token_account: all.token_account.key(),
+        required: true,
+        slot,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AccountExtension {
+    /// Require a memo on incoming transfers
+    MemoTransfer,
+    /// Lock owner actions made through CPI, the Token-2022 enable
+    /// instruction must follow in the same transaction
+    CpiGuard,
+}
+
+impl From<AccountExtension> for ExtensionType {
+    fn from(value: AccountExtension) -> Self {
+        match value {
+            AccountExtension::MemoTransfer => ExtensionType::MemoTransfer,
+            AccountExtension::CpiGuard => ExtensionType::CpiGuard,
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct ReallocAccount<'info> {
+    #[account(
+        mut,
+        token::authority = owner,
+        token::token_program = Token2022::id()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub owner: Signer<'info>,
+    /// CHECK: address is checked against the instructions sysvar id
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> ReallocAccount<'info> {
+    // Lamports still missing for the account to stay rent exempt once it holds `extensions`.
+    fn rent_difference(&self, extensions: &[ExtensionType]) -> Result<u64> {
+        let info = self.token_account.to_account_info();
+        let data = info.try_borrow_data()?;
+        let state = StateWithExtensions::<AccountState>::unpack(&data)?;
+
+        let mut all_extensions = state.get_extension_types()?;
+        all_extensions.extend_from_slice(extensions);
+        let needed_len = ExtensionType::try_calculate_account_len::<AccountState>(&all_extensions)?;
+
+        // Native accounts hold their token amount as lamports.
+        let native_amount = if state.base.is_native() {
+            state.base.amount
+        } else {
+            0
+        };
+        let reserve = info.lamports().saturating_sub(native_amount);
+
+        Ok(Rent::get()?
+            .minimum_balance(needed_len)
+            .saturating_sub(reserve))
+    }
+
+    fn transfer_cpi(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.system_program.to_account_info(),
+            Transfer {
+                from: self.payer.to_account_info(),
+                to: self.token_account.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler_to_realloc_account(
+    ctx: Context<ReallocAccount>,
+    extensions: Vec<AccountExtension>,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    require!(!extensions.is_empty(), ErrorCode::EmptyExtensionList);
+
+    let extension_types: Vec<ExtensionType> = extensions
+        .iter()
+        .map(|extension| (*extension).into())
+        .collect();
+
+    // Fund only the rent difference up front, reallocate then has nothing left to charge.
+    let lamports = all.rent_difference(&extension_types)?;
+    if lamports > 0 {
+        transfer(all.transfer_cpi(), lamports)?;
+    }
+
+    let ix = reallocate(
+        all.token_2022_program.key,
+        &all.token_account.key(),
+        all.payer.key,
+        all.owner.key,
+        &[],
+        &extension_types,
+    )?;
+
+    invoke(
+        &ix,
+        &[
+            all.token_account.to_account_info(),
+            all.payer.to_account_info(),
+            all.system_program.to_account_info(),
+            all.owner.to_account_info(),
+        ],
+    )?;
+
+    if extensions.contains(&AccountExtension::MemoTransfer) {
+        let ix = enable_required_transfer_memos(
+            all.token_2022_program.key,
+            &all.token_account.key(),
+            all.owner.key,
+            &[],
+        )?;
+
+        invoke(
+            &ix,
+            &[
+                all.token_account.to_account_info(),
+                all.owner.to_account_info(),
+            ],
+        )?;
+    }
+
+    if extensions.contains(&AccountExtension::CpiGuard) {
+        let ix = enable_cpi_guard(
+            all.token_2022_program.key,
+            &all.token_account.key(),
+            all.owner.key,
+            &[],
+        )?;
+
+        check_next_cpi_guard_toggle(&all.instructions.to_account_info(), &ix)?;
+    }
+
+    let slot = Clock::get()?.slot;
+    emit!(AccountReallocated {
+        mint: all.token_account.mint,
+        token_account: all.token_account.key(),
+        extensions: extension_types.into_iter().map(u16::from).collect(),
+        slot,
+    });
+    if extensions.contains(&AccountExtension::MemoTransfer) {
+        emit!(MemoRequirementChanged {
+            mint: all.token_account.mint,
+            token_account: all.token_account.key(),
+            required: true,
+            slot,
+        });
+    }
+    if extensions.contains(&AccountExtension::CpiGuard) {
+        emit!(CpiGuardToggleRequested {
+            mint: all.token_account.mint,
+            token_account: all.token_account.key(),
+            enabled: true,
+            slot,
+        });
+    }
+
     Ok(())
 }
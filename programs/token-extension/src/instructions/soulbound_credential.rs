@@ -0,0 +1,449 @@
+use {
+    crate::{
+        checks::check_not_frozen,
+        constants::{
+            CREDENTIAL_EXPIRES_AT_KEY, CREDENTIAL_HOLDER_KEY, CREDENTIAL_REVOKED_AT_KEY,
+            MAX_CLAIM_LEN, MAX_CREDENTIAL_CLAIMS, PROGRAM_CONFIG_SEED,
+        },
+        error::ErrorCode,
+        events::{CredentialIssued, CredentialRevoked},
+        instructions::permanent_delegate::check_permanent_delegate,
+        state::ProgramConfig,
+        utils::emit_mint_created,
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::{program::invoke, system_instruction},
+        system_program::{transfer, Transfer},
+    },
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token_2022::{burn, initialize_mint2, mint_to, Burn, InitializeMint2, MintTo},
+        token_interface::{Mint, Token2022, TokenAccount},
+    },
+    spl_token_2022::{
+        extension::{
+            metadata_pointer::instruction::initialize as initialize_metadata_pointer,
+            non_transferable::NonTransferable, BaseStateWithExtensions, ExtensionType,
+            StateWithExtensions,
+        },
+        instruction::{initialize_non_transferable_mint, initialize_permanent_delegate},
+        state::Mint as MintState,
+    },
+    spl_token_metadata_interface::{
+        instruction::{initialize as initialize_metadata, update_field},
+        state::{Field as MetaField, TokenMetadata},
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CredentialClaim {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CredentialStatus {
+    /// Wallet the credential was issued to
+    pub holder: Pubkey,
+    /// Holder account still holds the credential token
+    pub held: bool,
+    /// Expiry written at issuance
+    pub expires_at: i64,
+    /// Revocation time, a revoked mint never issues again
+    pub revoked_at: Option<i64>,
+    /// Held, not revoked and not expired
+    pub valid: bool,
+}
+
+#[derive(Accounts)]
+pub struct CreateCredentialMint<'info> {
+    #[account(
+        mut,
+        owner = System::id() @ ErrorCode::InvalidAccountOwner,
+        constraint = mint.data_is_empty() @ ErrorCode::AlreadyInUse
+    )]
+    pub mint: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub issuer: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+impl<'info> CreateCredentialMint<'info> {
+    fn initialize_mint_2_cpi(&self) -> CpiContext<'_, '_, '_, 'info, InitializeMint2<'info>> {
+        CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            InitializeMint2 {
+                mint: self.mint.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler_to_create_credential_mint(
+    ctx: Context<CreateCredentialMint>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let all = ctx.accounts;
+
+    require!(!name.is_empty(), ErrorCode::InvalidName);
+    require!(!symbol.is_empty(), ErrorCode::InvalidSymbol);
+    require!(!uri.is_empty(), ErrorCode::InvalidUri);
+
+    let mint_len = ExtensionType::try_calculate_account_len::<MintState>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::PermanentDelegate,
+        ExtensionType::MetadataPointer,
+    ])?;
+    let metadata = TokenMetadata {
+        update_authority: Some(all.issuer.key()).try_into()?,
+        mint: all.mint.key(),
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: vec![],
+    };
+    // Fund the metadata up front, Token-2022 reallocates but does not charge rent.
+    let lamports = Rent::get()?.minimum_balance(mint_len + metadata.tlv_size_of()?);
+
+    invoke(
+        &system_instruction::create_account(
+            all.payer.key,
+            all.mint.key,
+            lamports,
+            mint_len as u64,
+            &Token2022::id(),
+        ),
+        &[all.payer.to_account_info(), all.mint.to_account_info()],
+    )?;
+
+    let mint_infos = [all.mint.to_account_info()];
+    invoke(
+        &initialize_non_transferable_mint(all.token_2022_program.key, all.mint.key)?,
+        &mint_infos,
+    )?;
+    invoke(
+        &initialize_permanent_delegate(all.token_2022_program.key, all.mint.key, all.issuer.key)?,
+        &mint_infos,
+    )?;
+    invoke(
+        &initialize_metadata_pointer(
+            all.token_2022_program.key,
+            all.mint.key,
+            Some(all.issuer.key()),
+            Some(all.mint.key()),
+        )?,
+        &mint_infos,
+    )?;
+
+    initialize_mint2(all.initialize_mint_2_cpi(), 0, all.issuer.key, None)?;
+
+    invoke(
+        &initialize_metadata(
+            all.token_2022_program.key,
+            all.mint.key,
+            all.issuer.key,
+            all.mint.key,
+            all.issuer.key,
+            name,
+            symbol,
+            uri,
+        ),
+        &[all.mint.to_account_info(), all.issuer.to_account_info()],
+    )?;
+
+    emit_mint_created(&all.mint)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct IssueCredential<'info> {
+    #[account(
+        mut,
+        mint::authority = issuer,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub holder: SystemAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_2022_program
+    )]
+    pub holder_account: InterfaceAccount<'info, TokenAccount>,
+    pub issuer: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+// Metadata of a credential mint, which must be non-transferable.
+fn credential_metadata(mint: &AccountInfo) -> Result<TokenMetadata> {
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+    require!(
+        state.get_extension::<NonTransferable>().is_ok(),
+        ErrorCode::NotACredential
+    );
+    Ok(state.get_variable_len_extension::<TokenMetadata>()?)
+}
+
+fn metadata_value<'a>(metadata: &'a TokenMetadata, key: &str) -> Option<&'a str> {
+    metadata
+        .additional_metadata
+        .iter()
+        .find(|(field, _)| field == key)
+        .map(|(_, value)| value.as_str())
+}
+
+// Write `fields` to the credential metadata, topping up the rent for the larger metadata first.
+fn write_credential_fields<'info>(
+    mint: &AccountInfo<'info>,
+    issuer: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    fields: Vec<(String, String)>,
+) -> Result<()> {
+    let current = credential_metadata(mint)?;
+    let mut metadata = current.clone();
+    for (key, value) in &fields {
+        metadata.set_key_value(key.clone(), value.clone());
+    }
+
+    let new_len = mint.data_len() - current.tlv_size_of()? + metadata.tlv_size_of()?;
+    let lamports = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(mint.lamports());
+    if lamports > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: mint.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    for (key, value) in fields {
+        invoke(
+            &update_field(
+                &Token2022::id(),
+                mint.key,
+                issuer.key,
+                MetaField::Key(key),
+                value,
+            ),
+            &[mint.clone(), issuer.clone()],
+        )?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn handler_to_issue_credential(
+    ctx: Context<IssueCredential>,
+    expires_at: i64,
+    claims: Vec<CredentialClaim>,
+) -> Result<()> {
+    let all = ctx.accounts;
+    let clock = Clock::get()?;
+
+    require!(
+        expires_at > clock.unix_timestamp,
+        ErrorCode::InvalidTimestamp
+    );
+    require!(all.mint.supply == 0, ErrorCode::CredentialAlreadyIssued);
+    require!(
+        claims.len() <= MAX_CREDENTIAL_CLAIMS
+            && claims.iter().all(|claim| {
+                !claim.key.is_empty()
+                    && claim.key.len() <= MAX_CLAIM_LEN
+                    && claim.value.len() <= MAX_CLAIM_LEN
+                    && claim.key != CREDENTIAL_HOLDER_KEY
+                    && claim.key != CREDENTIAL_EXPIRES_AT_KEY
+                    && claim.key != CREDENTIAL_REVOKED_AT_KEY
+            }),
+        ErrorCode::InvalidClaims
+    );
+
+    // Supply drops back to zero on revocation, the metadata tells a used mint apart.
+    let metadata = credential_metadata(&all.mint.to_account_info())?;
+    require!(
+        metadata_value(&metadata, CREDENTIAL_REVOKED_AT_KEY).is_none(),
+        ErrorCode::CredentialRevoked
+    );
+    require!(
+        metadata_value(&metadata, CREDENTIAL_HOLDER_KEY).is_none(),
+        ErrorCode::CredentialAlreadyIssued
+    );
+
+    let mut fields = vec![
+        (
+            CREDENTIAL_HOLDER_KEY.to_string(),
+            all.holder.key().to_string(),
+        ),
+        (
+            CREDENTIAL_EXPIRES_AT_KEY.to_string(),
+            expires_at.to_string(),
+        ),
+    ];
+    fields.extend(claims.into_iter().map(|claim| (claim.key, claim.value)));
+
+    write_credential_fields(
+        &all.mint.to_account_info(),
+        &all.issuer.to_account_info(),
+        &all.payer.to_account_info(),
+        &all.system_program.to_account_info(),
+        fields,
+    )?;
+
+    mint_to(
+        CpiContext::new(
+            all.token_2022_program.to_account_info(),
+            MintTo {
+                mint: all.mint.to_account_info(),
+                to: all.holder_account.to_account_info(),
+                authority: all.issuer.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    emit!(CredentialIssued {
+        mint: all.mint.key(),
+        holder: all.holder.key(),
+        issuer: all.issuer.key(),
+        expires_at,
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeCredential<'info> {
+    #[account(
+        mut,
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub holder_account: InterfaceAccount<'info, TokenAccount>,
+    pub issuer: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub(crate) fn handler_to_revoke_credential(ctx: Context<RevokeCredential>) -> Result<()> {
+    let all = ctx.accounts;
+
+    check_permanent_delegate(&all.mint.to_account_info(), all.issuer.key)?;
+    check_not_frozen(&all.holder_account.to_account_info())?;
+    require!(all.holder_account.amount > 0, ErrorCode::InvalidAmount);
+
+    burn(
+        CpiContext::new(
+            all.token_2022_program.to_account_info(),
+            Burn {
+                mint: all.mint.to_account_info(),
+                from: all.holder_account.to_account_info(),
+                authority: all.issuer.to_account_info(),
+            },
+        ),
+        all.holder_account.amount,
+    )?;
+
+    write_credential_fields(
+        &all.mint.to_account_info(),
+        &all.issuer.to_account_info(),
+        &all.payer.to_account_info(),
+        &all.system_program.to_account_info(),
+        vec![(
+            CREDENTIAL_REVOKED_AT_KEY.to_string(),
+            Clock::get()?.unix_timestamp.to_string(),
+        )],
+    )?;
+
+    emit!(CredentialRevoked {
+        mint: all.mint.key(),
+        holder: all.holder_account.owner,
+        issuer: all.issuer.key(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VerifyCredential<'info> {
+    #[account(
+        mint::token_program = Token2022::id()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        token::mint = mint,
+        token::token_program = Token2022::id()
+    )]
+    pub holder_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+pub(crate) fn handle_verify_credential(ctx: Context<VerifyCredential>) -> Result<CredentialStatus> {
+    let all = ctx.accounts;
+
+    let metadata = credential_metadata(&all.mint.to_account_info())?;
+
+    let holder = metadata_value(&metadata, CREDENTIAL_HOLDER_KEY)
+        .and_then(|holder| holder.parse::<Pubkey>().ok())
+        .ok_or(ErrorCode::NotACredential)?;
+    let expires_at = metadata_value(&metadata, CREDENTIAL_EXPIRES_AT_KEY)
+        .and_then(|expires_at| expires_at.parse::<i64>().ok())
+        .ok_or(ErrorCode::NotACredential)?;
+    let revoked_at = metadata_value(&metadata, CREDENTIAL_REVOKED_AT_KEY)
+        .and_then(|revoked_at| revoked_at.parse::<i64>().ok());
+
+    let held = all.holder_account.owner == holder && all.holder_account.amount > 0;
+
+    Ok(CredentialStatus {
+        holder,
+        held,
+        expires_at,
+        revoked_at,
+        valid: held && revoked_at.is_none() && Clock::get()?.unix_timestamp < expires_at,
+    })
+}
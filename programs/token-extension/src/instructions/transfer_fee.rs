@@ -1,5 +1,12 @@
 use {
-    crate::error::ErrorCode,
+    crate::{
+        checks::{check_mint_extension, check_mint_len, check_not_frozen, check_transfer},
+        constants::PROGRAM_CONFIG_SEED,
+        error::ErrorCode,
+        events::{FeesHarvested, FeesWithdrawn, TokensMinted, TransferExecuted},
+        state::ProgramConfig,
+        utils::emit_mint_created,
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{program::invoke, system_instruction},
@@ -15,8 +22,10 @@ use {
                         transfer_checked_with_fee, withdraw_withheld_tokens_from_accounts,
                         withdraw_withheld_tokens_from_mint,
                     },
-                    BaseStateWithExtensions,
+                    transfer_fee::TransferFeeConfig as TransferFeeExtension,
+                    BaseStateWithExtensions, ExtensionType,
                 },
+                state::Mint as MintState,
             },
             InitializeMint2, MintTo, Token2022,
         },
@@ -40,6 +49,12 @@ pub struct TransferFeeConfig<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> TransferFeeConfig<'info> {
@@ -63,6 +78,12 @@ pub(crate) fn handler(
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_mint_len(
+        &all.mint.to_account_info(),
+        data_len,
+        &[ExtensionType::TransferFeeConfig],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -93,6 +114,8 @@ pub(crate) fn handler(
 
     initialize_mint2(all.initialize_mint_2_cpi(), 2, all.all_mint_role.key, None)?;
 
+    emit_mint_created(&all.mint)?;
+
     Ok(())
 }
 
@@ -113,6 +136,12 @@ pub struct MintToAccount<'info> {
     )]
     pub associated_token: InterfaceAccount<'info, TokenAccount>,
     pub authority: Signer<'info>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> MintToAccount<'info> {
@@ -131,7 +160,17 @@ impl<'info> MintToAccount<'info> {
 pub(crate) fn handler_for_mint_to(ctx: Context<MintToAccount>, amount: u64) -> Result<()> {
     let all = ctx.accounts;
 
+    check_not_frozen(&all.associated_token.to_account_info())?;
+
     mint_to(all.mint_to_cpi(), amount)?;
+
+    emit!(TokensMinted {
+        mint: all.mint.key(),
+        to: all.associated_token.key(),
+        amount,
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
 
@@ -156,6 +195,12 @@ pub struct TransferToAccount<'info> {
     pub to_acc: InterfaceAccount<'info, TokenAccount>,
     pub token_2022_program: Interface<'info, TokenInterface>,
     pub authority: Signer<'info>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 pub(crate) fn handler_for_transfer_to_account(
@@ -165,6 +210,15 @@ pub(crate) fn handler_for_transfer_to_account(
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_transfer(
+        &all.mint.to_account_info(),
+        &all.from_acc.to_account_info(),
+        &all.to_acc.to_account_info(),
+        amount,
+        Some(fee),
+        false,
+    )?;
+
     let ix = transfer_checked_with_fee(
         all.token_2022_program.key,
         &all.from_acc.key(),
@@ -187,6 +241,17 @@ pub(crate) fn handler_for_transfer_to_account(
             all.token_2022_program.to_account_info(),
         ],
     )?;
+
+    emit!(TransferExecuted {
+        mint: all.mint.key(),
+        from: all.from_acc.key(),
+        to: all.to_acc.key(),
+        amount,
+        fee,
+        memo: None,
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
 
@@ -205,6 +270,12 @@ pub struct WithdrawFromWithheldAccount<'info> {
     pub destination: InterfaceAccount<'info, TokenAccount>,
     pub token_2022_program: Interface<'info, TokenInterface>,
     pub authority: Signer<'info>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 pub(crate) fn handler_for_withdraw_withheld_account<'a>(
@@ -212,7 +283,11 @@ pub(crate) fn handler_for_withdraw_withheld_account<'a>(
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_mint_extension::<TransferFeeExtension>(&all.mint.to_account_info())?;
+    check_not_frozen(&all.destination.to_account_info())?;
+
     let (sources, mut infos) = filter_sources_account_info(ctx.remaining_accounts)?;
+    let balance_before = all.destination.amount;
 
     let ix = withdraw_withheld_tokens_from_accounts(
         all.token_2022_program.key,
@@ -230,6 +305,15 @@ pub(crate) fn handler_for_withdraw_withheld_account<'a>(
 
     invoke(&ix, &infos)?;
 
+    all.destination.reload()?;
+    emit!(FeesWithdrawn {
+        mint: all.mint.key(),
+        destination: all.destination.key(),
+        sources: sources.into_iter().copied().collect(),
+        amount: all.destination.amount.saturating_sub(balance_before),
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
 
@@ -241,6 +325,12 @@ pub struct HarvestWithheldToken<'info> {
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     pub token_2022_program: Interface<'info, TokenInterface>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 pub(crate) fn handler_for_harvest_withheld_token<'a>(
@@ -248,7 +338,10 @@ pub(crate) fn handler_for_harvest_withheld_token<'a>(
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_mint_extension::<TransferFeeExtension>(&all.mint.to_account_info())?;
+
     let (sources, mut infos) = filter_sources_account_info(ctx.remaining_accounts)?;
+    let withheld_before = mint_withheld_amount(&all.mint.to_account_info())?;
 
     let ix =
         harvest_withheld_tokens_to_mint(all.token_2022_program.key, &all.mint.key(), &sources)?;
@@ -258,6 +351,13 @@ pub(crate) fn handler_for_harvest_withheld_token<'a>(
 
     invoke(&ix, &infos)?;
 
+    emit!(FeesHarvested {
+        mint: all.mint.key(),
+        accounts: sources.into_iter().copied().collect(),
+        amount: mint_withheld_amount(&all.mint.to_account_info())?.saturating_sub(withheld_before),
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
 
@@ -277,6 +377,12 @@ pub struct WithdrawFromWithheldMint<'info> {
     pub destination: InterfaceAccount<'info, TokenAccount>,
     pub token_2022_program: Interface<'info, TokenInterface>,
     pub authority: Signer<'info>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 pub(crate) fn handler_for_withdraw_withheld_mint(
@@ -284,6 +390,11 @@ pub(crate) fn handler_for_withdraw_withheld_mint(
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_mint_extension::<TransferFeeExtension>(&all.mint.to_account_info())?;
+    check_not_frozen(&all.destination.to_account_info())?;
+
+    let balance_before = all.destination.amount;
+
     let ix = withdraw_withheld_tokens_from_mint(
         all.token_2022_program.key,
         &all.mint.key(),
@@ -302,6 +413,15 @@ pub(crate) fn handler_for_withdraw_withheld_mint(
         ],
     )?;
 
+    all.destination.reload()?;
+    emit!(FeesWithdrawn {
+        mint: all.mint.key(),
+        destination: all.destination.key(),
+        sources: vec![all.mint.key()],
+        amount: all.destination.amount.saturating_sub(balance_before),
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
 
@@ -336,3 +456,11 @@ fn filter_sources_account_info<'a, 'info>(
     }
     Ok((sources, acc_infos))
 }
+
+fn mint_withheld_amount(mint: &AccountInfo) -> Result<u64> {
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+    let transfer_fee_config = state.get_extension::<TransferFeeExtension>()?;
+
+    Ok(u64::from(transfer_fee_config.withheld_amount))
+}
@@ -1,5 +1,12 @@
 use {
-    crate::error::ErrorCode,
+    crate::{
+        checks::{check_mint_len, check_transfer},
+        constants::PROGRAM_CONFIG_SEED,
+        error::ErrorCode,
+        events::TransferExecuted,
+        state::ProgramConfig,
+        utils::{emit_mint_created, expected_transfer_fee},
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{program::invoke, system_instruction},
@@ -7,8 +14,9 @@ use {
     anchor_spl::{
         token_2022::{self, initialize_mint2, InitializeMint2, Token2022},
         token_interface::{
-            spl_token_2022::extension::transfer_hook::instruction::initialize,
-            spl_token_2022::onchain::invoke_transfer_checked, Mint, TokenAccount,
+            spl_token_2022::extension::{transfer_hook::instruction::initialize, ExtensionType},
+            spl_token_2022::onchain::invoke_transfer_checked,
+            Mint, TokenAccount,
         },
     },
 };
@@ -27,6 +35,12 @@ pub struct InitializeHookMint<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 impl<'info> InitializeHookMint<'info> {
@@ -47,6 +61,12 @@ pub(crate) fn handler_to_initialize_hook_mint(
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_mint_len(
+        &all.mint.to_account_info(),
+        mint_len,
+        &[ExtensionType::TransferHook],
+    )?;
+
     invoke(
         &system_instruction::create_account(
             all.payer.key,
@@ -75,6 +95,9 @@ pub(crate) fn handler_to_initialize_hook_mint(
     )?;
 
     initialize_mint2(all.initialize_mint_2_cpi(), 2, all.all_mint_role.key, None)?;
+
+    emit_mint_created(&all.mint)?;
+
     Ok(())
 }
 
@@ -99,6 +122,12 @@ pub struct TransferHookToken<'info> {
     pub to_acc: InterfaceAccount<'info, TokenAccount>,
     pub token_2022_program: Program<'info, Token2022>,
     pub authority: Signer<'info>,
+    #[account(
+        seeds = [PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
 }
 
 pub(crate) fn handler_to_transfer_token<'a>(
@@ -108,6 +137,15 @@ pub(crate) fn handler_to_transfer_token<'a>(
 ) -> Result<()> {
     let all = ctx.accounts;
 
+    check_transfer(
+        &all.mint.to_account_info(),
+        &all.from_acc.to_account_info(),
+        &all.to_acc.to_account_info(),
+        amount,
+        None,
+        false,
+    )?;
+
     invoke_transfer_checked(
         all.token_2022_program.key,
         all.from_acc.to_account_info(),
@@ -119,5 +157,16 @@ pub(crate) fn handler_to_transfer_token<'a>(
         decimals,
         &[],
     )?;
+
+    emit!(TransferExecuted {
+        mint: all.mint.key(),
+        from: all.from_acc.key(),
+        to: all.to_acc.key(),
+        amount,
+        fee: expected_transfer_fee(&all.mint.to_account_info(), amount)?,
+        memo: None,
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
@@ -1,12 +1,16 @@
+pub mod checks;
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
 
 pub use constants::*;
 pub use instructions::*;
+pub use state::*;
 
 declare_id!("2A4udxjXYzZnJgX65cbVpr3421bpWrrjUBNey1iWrEiy");
 
@@ -15,6 +19,29 @@ pub mod token_extension {
 
     use super::*;
 
+    // Create the program config PDA, the upgrade authority becomes its admin.
+    pub fn initialize_program_config(ctx: Context<InitializeProgramConfig>) -> Result<()> {
+        program_admin::handler_to_initialize_program_config(ctx)
+    }
+
+    // Propose a new program config admin, who has to accept the role. None cancels the proposal.
+    pub fn propose_admin(
+        ctx: Context<UpdateProgramConfig>,
+        new_admin: Option<Pubkey>,
+    ) -> Result<()> {
+        program_admin::handler_to_propose_admin(ctx, new_admin)
+    }
+
+    // Accept the admin role proposed by the current admin.
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        program_admin::handler_to_accept_admin(ctx)
+    }
+
+    // Pause or resume the state changing instructions of this program.
+    pub fn set_paused(ctx: Context<UpdateProgramConfig>, paused: bool) -> Result<()> {
+        program_admin::handler_to_set_paused(ctx, paused)
+    }
+
     // Initialize Mint Close Authority, then it is possible to close token mint.
     pub fn mint_close_authority(ctx: Context<MintCloseAuthority>, data_len: u64) -> Result<()> {
         mint_close_authority::handler(ctx, data_len)
@@ -25,6 +52,18 @@ pub mod token_extension {
         close_mint_account::handler(ctx)
     }
 
+    // Close a mint after checking the close authority and zero supply, withdrawing withheld fees first.
+    pub fn safe_close_mint(ctx: Context<SafeCloseMint>) -> Result<()> {
+        close_mint_account::handler_to_safe_close_mint(ctx)
+    }
+
+    // Harvest withheld fees and close the empty token accounts passed as remaining accounts, reporting the skipped ones.
+    pub fn close_token_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseTokenAccounts<'info>>,
+    ) -> Result<CloseReport> {
+        close_token_accounts::handler_to_close_token_accounts(ctx)
+    }
+
     // Initialize transfer fee config and set respective authority to withdraw tokens.
     pub fn transfer_fee_config(
         ctx: Context<TransferFeeConfig>,
@@ -73,19 +112,68 @@ pub mod token_extension {
         transfer_fee::handler_for_withdraw_withheld_mint(ctx)
     }
 
-    // Create a mint with default frozen account.
-    pub fn default_account_state(ctx: Context<DefaultAccountState>, data_len: u64) -> Result<()> {
-        default_account_state::handler_for_default_account_state(ctx, data_len)
+    // Create a mint whose new accounts start in the given default state.
+    pub fn default_account_state(
+        ctx: Context<DefaultAccountState>,
+        data_len: u64,
+        state: DefaultState,
+    ) -> Result<()> {
+        default_account_state::handler_for_default_account_state(ctx, data_len, state)
     }
 
-    // Update the mint with Initialized state
-    pub fn update_default_account_state(
-        ctx: Context<UpdateDefaultAccountState>,
-        account_state: u8,
+    // Update the mint default state, signed by the freeze authority or its multisig signers.
+    pub fn update_default_account_state<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateDefaultAccountState<'info>>,
+        account_state: DefaultState,
     ) -> Result<()> {
         default_account_state::handler_for_update_default_account_state(ctx, account_state)
     }
 
+    // Rotate or remove any mint authority, signed by the current authority or its multisig signers.
+    pub fn set_authority<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateAuthority<'info>>,
+        kind: AuthorityKind,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        authority::handler_to_set_authority(ctx, kind, new_authority)
+    }
+
+    // Hand the freeze authority of a default frozen mint to the program for KYC onboarding.
+    pub fn initialize_kyc(ctx: Context<InitializeKyc>) -> Result<()> {
+        kyc_onboarding::handler_to_initialize_kyc(ctx)
+    }
+
+    // Create an access request for a frozen token account owned by the wallet.
+    pub fn request_access(ctx: Context<RequestAccess>) -> Result<()> {
+        kyc_onboarding::handler_to_request_access(ctx)
+    }
+
+    // Compliance officer approves the access request and the token account gets thawed.
+    pub fn approve_access(ctx: Context<ReviewAccess>) -> Result<()> {
+        kyc_onboarding::handler_to_approve_access(ctx)
+    }
+
+    // Compliance officer revokes an approved access and the token account gets frozen again.
+    pub fn revoke_access(ctx: Context<ReviewAccess>) -> Result<()> {
+        kyc_onboarding::handler_to_revoke_access(ctx)
+    }
+
+    // Freeze a batch of token accounts and record the reason code for each of them.
+    pub fn freeze_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, BatchFreeze<'info>>,
+        reason_code: u16,
+    ) -> Result<()> {
+        batch_freeze::handler_to_freeze_accounts(ctx, reason_code)
+    }
+
+    // Thaw a batch of token accounts and record the reason code for each of them.
+    pub fn thaw_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, BatchFreeze<'info>>,
+        reason_code: u16,
+    ) -> Result<()> {
+        batch_freeze::handler_to_thaw_accounts(ctx, reason_code)
+    }
+
     // Create a account whose ownership can't transferred
     pub fn immutable_owner(
         ctx: Context<ImmutableOwner>,
@@ -105,14 +193,60 @@ pub mod token_extension {
         non_transferable_tokens::handler(ctx, mint_len, account_len)
     }
 
+    // Create a soulbound credential mint: non-transferable, issuer as permanent delegate, with metadata.
+    pub fn create_credential_mint(
+        ctx: Context<CreateCredentialMint>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        soulbound_credential::handler_to_create_credential_mint(ctx, name, symbol, uri)
+    }
+
+    // Issue the credential to a holder, writing expiry and claims into the mint metadata.
+    pub fn issue_credential(
+        ctx: Context<IssueCredential>,
+        expires_at: i64,
+        claims: Vec<CredentialClaim>,
+    ) -> Result<()> {
+        soulbound_credential::handler_to_issue_credential(ctx, expires_at, claims)
+    }
+
+    // Revoke a credential by burning it through the issuer's permanent delegate.
+    pub fn revoke_credential(ctx: Context<RevokeCredential>) -> Result<()> {
+        soulbound_credential::handler_to_revoke_credential(ctx)
+    }
+
+    // Report whether the holder account still holds an unexpired credential.
+    pub fn verify_credential(ctx: Context<VerifyCredential>) -> Result<CredentialStatus> {
+        soulbound_credential::handle_verify_credential(ctx)
+    }
+
     // Enable a account with required memo on transfer.
     pub fn enable_memo(ctx: Context<EnableMemo>, account_len: u64) -> Result<()> {
         memo_transfer::handler_to_enable_memo(ctx, account_len)
     }
 
-    // Demonstrate memo usage while transfering tokens.
-    pub fn memo_transfer(ctx: Context<MemoTransfer>, amount: u64, decimals: u8) -> Result<()> {
-        memo_transfer::handler_to_memo_transfer(ctx, amount, decimals)
+    // Stop requiring memos on incoming transfers.
+    pub fn disable_memo(ctx: Context<DisableMemo>) -> Result<()> {
+        memo_transfer::handler_to_disable_memo(ctx)
+    }
+
+    // Report whether a token account requires incoming memos and has CPI Guard enabled.
+    pub fn transfer_requirements(
+        ctx: Context<GetTransferRequirements>,
+    ) -> Result<TransferRequirements> {
+        memo_transfer::handle_transfer_requirements(ctx)
+    }
+
+    // Transfer tokens with a caller supplied memo, either free text or a validated payment reference.
+    pub fn memo_transfer(
+        ctx: Context<MemoTransfer>,
+        amount: u64,
+        decimals: u8,
+        memo: TransferMemo,
+    ) -> Result<()> {
+        memo_transfer::handler_to_memo_transfer(ctx, amount, decimals, memo)
     }
 
     // Instruction to add some new extension to already extensioned account.
@@ -120,6 +254,14 @@ pub mod token_extension {
         realloc::handler_to_realloc(ctx)
     }
 
+    // Reallocate a token account for several extensions at once and enable them, charging only the rent difference.
+    pub fn realloc_account(
+        ctx: Context<ReallocAccount>,
+        extensions: Vec<AccountExtension>,
+    ) -> Result<()> {
+        realloc::handler_to_realloc_account(ctx, extensions)
+    }
+
     // Initialize interest bearing tokens to accumulate interest based on the timestamp in the network.
     pub fn interest_bearing_token(
         ctx: Context<InterestBearingToken>,
@@ -134,6 +276,59 @@ pub mod token_extension {
         interest_bearing_token::handle_amount_to_ui(ctx, amount)
     }
 
+    // Update the interest rate of an interest bearing mint, signed by the rate authority.
+    pub fn update_rate(ctx: Context<UpdateRate>, rate: i16) -> Result<()> {
+        interest_bearing_token::handler_to_update_rate(ctx, rate)
+    }
+
+    // Instruction used to convert a UI amount (with the interest) back into the raw token amount.
+    pub fn ui_amount_to_amount(ctx: Context<UIToAmount>, ui_amount: String) -> Result<u64> {
+        interest_bearing_token::handle_ui_to_amount(ctx, ui_amount)
+    }
+
+    // Project an amount with the interest accrued up to now or a future timestamp, along with the mint rates.
+    pub fn project_interest(
+        ctx: Context<ProjectInterest>,
+        amount: u64,
+        unix_timestamp: Option<i64>,
+    ) -> Result<InterestProjection> {
+        interest_bearing_token::handle_project_interest(ctx, amount, unix_timestamp)
+    }
+
+    // Move the interest rate authority to a governance PDA with rate bounds and a timelock.
+    pub fn initialize_rate_governance(
+        ctx: Context<InitializeRateGovernance>,
+        min_rate: i16,
+        max_rate: i16,
+        max_change_per_period: u16,
+        period: i64,
+        timelock: i64,
+    ) -> Result<()> {
+        interest_rate_governance::handler_to_initialize_rate_governance(
+            ctx,
+            min_rate,
+            max_rate,
+            max_change_per_period,
+            period,
+            timelock,
+        )
+    }
+
+    // Propose a new interest rate within the governance bounds.
+    pub fn propose_rate(ctx: Context<ProposeRate>, rate: i16) -> Result<()> {
+        interest_rate_governance::handler_to_propose_rate(ctx, rate)
+    }
+
+    // Withdraw the pending interest rate before it is executed.
+    pub fn cancel_rate(ctx: Context<ProposeRate>) -> Result<()> {
+        interest_rate_governance::handler_to_cancel_rate(ctx)
+    }
+
+    // Apply the pending interest rate once the timelock has elapsed.
+    pub fn execute_rate(ctx: Context<ExecuteRate>) -> Result<()> {
+        interest_rate_governance::handler_to_execute_rate(ctx)
+    }
+
     // Initialize permanent account delegate for a mint.
     pub fn permanent_delegate(ctx: Context<PermanentDelegate>, mint_len: u64) -> Result<()> {
         permanent_delegate::handler_to_permanent_delegate(ctx, mint_len)
@@ -149,7 +344,51 @@ pub mod token_extension {
         permanent_delegate::handler_to_burn(ctx, amount)
     }
 
-    // Initialize token account with CPI Guard or Immutable Owner or Required Memo on Transfer
+    // Move tokens from any holder to a treasury using the permanent delegate, with a reason memo.
+    pub fn clawback(ctx: Context<Clawback>, amount: u64, reason: String) -> Result<()> {
+        permanent_delegate::handler_to_clawback(ctx, amount, reason)
+    }
+
+    // Move the permanent delegate to a governance PDA controlled by an M-of-N signer set.
+    pub fn initialize_delegate_governance(
+        ctx: Context<InitializeDelegateGovernance>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        permanent_delegate_governance::handler_to_initialize_delegate_governance(
+            ctx, signers, threshold,
+        )
+    }
+
+    // Propose a burn or clawback through the governed permanent delegate, expiring after ttl seconds.
+    pub fn propose_delegate_action(
+        ctx: Context<ProposeDelegateAction>,
+        action: DelegateAction,
+        amount: u64,
+        reason: String,
+        ttl: i64,
+    ) -> Result<()> {
+        permanent_delegate_governance::handler_to_propose_delegate_action(
+            ctx, action, amount, reason, ttl,
+        )
+    }
+
+    // Approve an active delegate proposal.
+    pub fn approve_delegate_proposal(ctx: Context<ReviewDelegateProposal>) -> Result<()> {
+        permanent_delegate_governance::handler_to_approve_delegate_proposal(ctx)
+    }
+
+    // Cancel an active delegate proposal.
+    pub fn cancel_delegate_proposal(ctx: Context<ReviewDelegateProposal>) -> Result<()> {
+        permanent_delegate_governance::handler_to_cancel_delegate_proposal(ctx)
+    }
+
+    // Execute a delegate proposal once it reached the approval threshold.
+    pub fn execute_delegate_proposal(ctx: Context<ExecuteDelegateProposal>) -> Result<()> {
+        permanent_delegate_governance::handler_to_execute_delegate_proposal(ctx)
+    }
+
+    // Create and initialize a token account sized for the given extensions, CPI Guard is turned on with enable_cpi_guard.
     pub fn initialize_token_account_with(
         ctx: Context<IntializeTokenAccount>,
         account_len: u64,
@@ -157,11 +396,29 @@ pub mod token_extension {
         cpi_guard::handler_to_initialize_token_account(ctx, account_len)
     }
 
+    // Create a token account for an existing mint with any mix of account extensions and an optional delegate.
+    pub fn create_token_account(
+        ctx: Context<CreateTokenAccount>,
+        options: TokenAccountOptions,
+    ) -> Result<()> {
+        create_token_account::handler_to_create_token_account(ctx, options)
+    }
+
     // Demonstrate CPI guard and without CPI guard account using transfer token ix and use of opaque program.
     pub fn transfer_token(ctx: Context<TransferToken>, amount: u64, decimals: u8) -> Result<()> {
         cpi_guard::handler_to_transfer_token(ctx, amount, decimals)
     }
 
+    // Make room for CPI Guard, the next instruction must be the Token-2022 enable instruction.
+    pub fn enable_cpi_guard(ctx: Context<ToggleCpiGuard>) -> Result<()> {
+        cpi_guard::handler_to_enable_cpi_guard(ctx)
+    }
+
+    // Make room for CPI Guard, the next instruction must be the Token-2022 disable instruction.
+    pub fn disable_cpi_guard(ctx: Context<ToggleCpiGuard>) -> Result<()> {
+        cpi_guard::handler_to_disable_cpi_guard(ctx)
+    }
+
     // Initialize transfer hook for mint.
     pub fn initialize_hook_mint(
         ctx: Context<InitializeHookMint>,
@@ -204,13 +461,21 @@ pub mod token_extension {
     }
 
     // 🚧 Confidential extension work is in progress 🚧
-    // Initialize confidential mint
+    // Initialize confidential mint with an approval policy and an optional auditor ElGamal pubkey.
     pub fn initialize_confidential_mint(
         ctx: Context<InitializeConfidentialMint>,
         mint_len: u64,
         decimals: u8,
+        auto_approve_new_accounts: bool,
+        auditor_elgamal_pubkey: Option<[u8; 32]>,
     ) -> Result<()> {
-        confidential_transfer::handler_to_initialize_confidential_mint(ctx, mint_len, decimals)
+        confidential_transfer::handler_to_initialize_confidential_mint(
+            ctx,
+            mint_len,
+            decimals,
+            auto_approve_new_accounts,
+            auditor_elgamal_pubkey,
+        )
     }
 
     // 🚧 Confidential extension work is in progress 🚧
@@ -222,6 +487,78 @@ pub mod token_extension {
         confidential_transfer::handler_to_initialize_confidential_account(ctx, account_len)
     }
 
+    // Approve a configured confidential account when the mint requires manual approval.
+    pub fn approve_confidential_account(ctx: Context<ApproveConfidentialAccount>) -> Result<()> {
+        confidential_transfer::handler_to_approve_confidential_account(ctx)
+    }
+
+    // Update the approval policy and auditor ElGamal pubkey of a confidential mint.
+    pub fn update_confidential_mint(
+        ctx: Context<UpdateConfidentialMint>,
+        auto_approve_new_accounts: bool,
+        auditor_elgamal_pubkey: Option<[u8; 32]>,
+    ) -> Result<()> {
+        confidential_transfer::handler_to_update_confidential_mint(
+            ctx,
+            auto_approve_new_accounts,
+            auditor_elgamal_pubkey,
+        )
+    }
+
+    // 🚧 Confidential extension work is in progress 🚧
+    // Initialize a mint with transfer fee, confidential transfer and confidential transfer fee config.
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_confidential_transfer_fee_mint(
+        ctx: Context<InitializeConfidentialTransferFeeMint>,
+        mint_len: u64,
+        decimals: u8,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+        auto_approve_new_accounts: bool,
+        auditor_elgamal_pubkey: Option<[u8; 32]>,
+        withdraw_withheld_authority_elgamal_pubkey: [u8; 32],
+    ) -> Result<()> {
+        confidential_transfer_fee::handler_to_initialize_confidential_transfer_fee_mint(
+            ctx,
+            mint_len,
+            decimals,
+            transfer_fee_basis_points,
+            maximum_fee,
+            auto_approve_new_accounts,
+            auditor_elgamal_pubkey,
+            withdraw_withheld_authority_elgamal_pubkey,
+        )
+    }
+
+    // Harvest encrypted withheld tokens from accounts to the mint.
+    pub fn harvest_confidential_withheld_token<'a>(
+        ctx: Context<'_, '_, '_, 'a, HarvestConfidentialWithheldToken<'a>>,
+    ) -> Result<()> {
+        confidential_transfer_fee::handler_to_harvest_confidential_withheld_token(ctx)
+    }
+
+    // Withdraw encrypted withheld tokens from mint using a pre-verified equality proof.
+    pub fn withdraw_confidential_withheld_mint(
+        ctx: Context<WithdrawConfidentialWithheldMint>,
+        new_decryptable_available_balance: [u8; 36],
+    ) -> Result<()> {
+        confidential_transfer_fee::handler_to_withdraw_confidential_withheld_mint(
+            ctx,
+            new_decryptable_available_balance,
+        )
+    }
+
+    // Withdraw encrypted withheld tokens from accounts using a pre-verified equality proof.
+    pub fn withdraw_confidential_withheld_account<'a>(
+        ctx: Context<'_, '_, '_, 'a, WithdrawConfidentialWithheldAccount<'a>>,
+        new_decryptable_available_balance: [u8; 36],
+    ) -> Result<()> {
+        confidential_transfer_fee::handler_to_withdraw_confidential_withheld_account(
+            ctx,
+            new_decryptable_available_balance,
+        )
+    }
+
     // Initialize only group pointer
     // Fix Required: due to invalid instruction error for spl_token_group_interface::instruction::initialize_group the code commented out
     pub fn initialize_group_pointer(
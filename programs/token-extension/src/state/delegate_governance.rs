@@ -0,0 +1,52 @@
+use {crate::constants::MAX_DELEGATE_SIGNERS, anchor_lang::prelude::*};
+
+#[account]
+#[derive(InitSpace)]
+pub struct DelegateGovernance {
+    /// Mint whose permanent delegate is this account
+    pub mint: Pubkey,
+    /// Signer set allowed to propose and approve delegate actions
+    #[max_len(MAX_DELEGATE_SIGNERS)]
+    pub signers: Vec<Pubkey>,
+    /// Number of approvals required to execute a proposal
+    pub threshold: u8,
+    /// Number of proposals created so far, used as the next proposal id
+    pub proposal_count: u64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum DelegateAction {
+    /// Burn `amount` from the source account
+    Burn,
+    /// Move `amount` from the source account to the treasury
+    Clawback,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum ProposalStatus {
+    Active,
+    Executed,
+    Cancelled,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DelegateProposal {
+    pub governance: Pubkey,
+    pub id: u64,
+    pub action: DelegateAction,
+    /// Token account the tokens are taken from
+    pub from: Pubkey,
+    /// Destination token account, only set for clawbacks
+    pub treasury: Option<Pubkey>,
+    pub amount: u64,
+    #[max_len(256)]
+    pub reason: String,
+    pub proposer: Pubkey,
+    #[max_len(MAX_DELEGATE_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+    pub expires_at: i64,
+    pub status: ProposalStatus,
+    pub bump: u8,
+}
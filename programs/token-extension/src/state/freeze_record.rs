@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct FreezeRecord {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    /// Whether the last recorded action froze the account
+    pub frozen: bool,
+    /// Operator supplied reason code of the last action
+    pub reason_code: u16,
+    /// Freeze authority that performed the last action
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub bump: u8,
+}
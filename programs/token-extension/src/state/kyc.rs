@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct KycConfig {
+    /// Default-frozen mint whose freeze authority is this account
+    pub mint: Pubkey,
+    /// Authority allowed to approve and revoke access requests
+    pub compliance_officer: Pubkey,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum AccessStatus {
+    Pending,
+    Approved,
+    Revoked,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AccessRequest {
+    pub mint: Pubkey,
+    /// Token account to be thawed once the request is approved
+    pub token_account: Pubkey,
+    /// Owner of the token account who created the request
+    pub wallet: Pubkey,
+    pub status: AccessStatus,
+    pub requested_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
@@ -1 +1,11 @@
+pub mod delegate_governance;
+pub mod freeze_record;
+pub mod kyc;
+pub mod program_config;
+pub mod rate_governance;
 
+pub use delegate_governance::*;
+pub use freeze_record::*;
+pub use kyc::*;
+pub use program_config::*;
+pub use rate_governance::*;
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramConfig {
+    /// Authority allowed to pause the program and hand over the admin role
+    pub admin: Pubkey,
+    /// Admin proposed by the current admin, waiting to accept the role
+    pub pending_admin: Option<Pubkey>,
+    /// State changing instructions are rejected while set
+    pub paused: bool,
+    pub bump: u8,
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RateGovernance {
+    /// Interest bearing mint governed by this account
+    pub mint: Pubkey,
+    /// Authority allowed to propose new rates
+    pub admin: Pubkey,
+    /// Lowest rate that can be proposed, in basis points
+    pub min_rate: i16,
+    /// Highest rate that can be proposed, in basis points
+    pub max_rate: i16,
+    /// Maximum total rate change allowed within one period, in basis points
+    pub max_change_per_period: u16,
+    /// Length of a rate change period in seconds
+    pub period: i64,
+    /// Delay in seconds between a proposal and its execution
+    pub timelock: i64,
+    /// Start of the current rate change period
+    pub period_start: i64,
+    /// Total rate change already executed within the current period, in basis points
+    pub period_change: u16,
+    /// Rate waiting for the timelock to elapse
+    pub pending_rate: Option<i16>,
+    /// Timestamp of the pending proposal
+    pub proposed_at: i64,
+    pub bump: u8,
+}
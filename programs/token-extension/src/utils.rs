@@ -0,0 +1,142 @@
+use {
+    crate::{
+        error::ErrorCode,
+        events::{MintCreated, TokenAccountCreated},
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::{
+            instruction::Instruction,
+            program_pack::Pack,
+            sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+        },
+    },
+    anchor_spl::{
+        token_2022::spl_token_2022::{
+            extension::{
+                transfer_fee::TransferFeeConfig, BaseState, BaseStateWithExtensions,
+                StateWithExtensions,
+            },
+            state::{Account as AccountState, Mint as MintState, Multisig},
+        },
+        token_interface::Token2022,
+    },
+};
+
+// Returns the multisig signers backing `authority`, or nothing when `authority` signed itself.
+// Token-2022 still validates the signers, this only surfaces a clear error before the CPI.
+pub(crate) fn authority_signers<'info>(
+    authority: &AccountInfo<'info>,
+    rem_accs: &[AccountInfo<'info>],
+) -> Result<Vec<AccountInfo<'info>>> {
+    if authority.is_signer {
+        return Ok(Vec::new());
+    }
+
+    require_keys_eq!(
+        *authority.owner,
+        Token2022::id(),
+        ErrorCode::MissingAuthoritySignature
+    );
+
+    let data = authority.try_borrow_data()?;
+    let multisig =
+        Multisig::unpack(&data).map_err(|_| error!(ErrorCode::MissingAuthoritySignature))?;
+    let valid_signers = &multisig.signers[..multisig.n as usize];
+
+    // A signer passed twice only counts once towards the threshold.
+    let mut signers: Vec<AccountInfo<'info>> = Vec::new();
+    for acc in rem_accs {
+        if acc.is_signer
+            && valid_signers.contains(acc.key)
+            && !signers.iter().any(|signer| signer.key == acc.key)
+        {
+            signers.push(acc.clone());
+        }
+    }
+
+    require!(
+        signers.len() >= multisig.m as usize,
+        ErrorCode::MissingAuthoritySignature
+    );
+
+    Ok(signers)
+}
+
+// Token-2022 refuses to toggle CPI Guard from a CPI, so the toggle has to be the
+// next top level instruction of the same transaction.
+pub(crate) fn check_next_cpi_guard_toggle(
+    instructions: &AccountInfo,
+    expected: &Instruction,
+) -> Result<()> {
+    let current = load_current_index_checked(instructions)?;
+    let next = load_instruction_at_checked(current as usize + 1, instructions)
+        .map_err(|_| ErrorCode::MissingCpiGuardToggle)?;
+
+    require!(
+        next.program_id == expected.program_id
+            && next.data == expected.data
+            && next.accounts.first().map(|meta| meta.pubkey)
+                == expected.accounts.first().map(|meta| meta.pubkey),
+        ErrorCode::MissingCpiGuardToggle
+    );
+
+    Ok(())
+}
+
+// Announce a freshly initialized mint with its extensions and authorities.
+pub(crate) fn emit_mint_created(mint: &AccountInfo) -> Result<()> {
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+
+    emit!(MintCreated {
+        mint: mint.key(),
+        extensions: extension_discriminants(&state)?,
+        mint_authority: state.base.mint_authority.into(),
+        freeze_authority: state.base.freeze_authority.into(),
+        decimals: state.base.decimals,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+// Announce a freshly initialized token account with its extensions.
+pub(crate) fn emit_token_account_created(token_account: &AccountInfo) -> Result<()> {
+    let data = token_account.try_borrow_data()?;
+    let state = StateWithExtensions::<AccountState>::unpack(&data)?;
+
+    emit!(TokenAccountCreated {
+        mint: state.base.mint,
+        token_account: token_account.key(),
+        owner: state.base.owner,
+        extensions: extension_discriminants(&state)?,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn extension_discriminants<S: BaseState>(
+    state: &StateWithExtensions<S>,
+) -> Result<Vec<u16>> {
+    Ok(state
+        .get_extension_types()?
+        .into_iter()
+        .map(u16::from)
+        .collect())
+}
+
+// Fee Token-2022 withholds on a transfer of `amount`, zero for mints without a transfer fee.
+pub(crate) fn expected_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+
+    let Ok(transfer_fee_config) = state.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+
+    transfer_fee_config
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or_else(|| error!(ErrorCode::InvalidAmount))
+}
@@ -0,0 +1,156 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenExtension } from "../target/types/token_extension";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  getMintLen,
+  getAccount,
+  createAssociatedTokenAccountIdempotent,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  assert,
+  fetchAdminKeypair,
+  fetchPayerKeypair,
+  fetchReceiverKeypair,
+  fetchReceiver2Keypair,
+  initializeProgramConfig,
+  runTest,
+  sendAndConfirmTransaction,
+} from "./utils";
+import Debug from "debug";
+
+const log = Debug("log: batchFreeze");
+
+describe("✅ tokenExtension: batch freeze and thaw", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
+
+  it(
+    "thaw and freeze accounts with reason codes",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      const receiver = fetchReceiverKeypair();
+
+      const receiver2 = fetchReceiver2Keypair();
+
+      await airdrop(provider, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+      log("Mint", mint.publicKey.toBase58());
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .defaultAccountState(
+            new anchor.BN(getMintLen([ExtensionType.DefaultAccountState])),
+            { frozen: {} }
+          )
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            payer: payer.publicKey,
+            allMintRole: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .transaction(),
+        signers: [admin, mint, payer],
+      });
+
+      const tokenAccounts = await Promise.all(
+        [receiver, receiver2].map((wallet) =>
+          createAssociatedTokenAccountIdempotent(
+            provider.connection,
+            payer,
+            mint.publicKey,
+            wallet.publicKey,
+            { commitment: "finalized", skipPreflight: true },
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      );
+
+      const remainingAccounts = tokenAccounts.flatMap((tokenAccount) => {
+        const [freezeRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+          [Buffer.from("freeze-record"), tokenAccount.toBuffer()],
+          program.programId
+        );
+        return [
+          { pubkey: tokenAccount, isSigner: false, isWritable: true },
+          { pubkey: freezeRecord, isSigner: false, isWritable: true },
+        ];
+      });
+
+      // A record address funded up front must not block freezing its token account.
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: new anchor.web3.Transaction().add(
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: payer.publicKey,
+            toPubkey: remainingAccounts[1].pubkey,
+            lamports: 1_000_000,
+          })
+        ),
+        signers: [payer],
+      });
+
+      const accounts = {
+        programConfig,
+        mint: mint.publicKey,
+        freezeAuthority: admin.publicKey,
+        payer: payer.publicKey,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      };
+
+      const thawTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .thawAccounts(1)
+          .accounts(accounts)
+          .remainingAccounts(remainingAccounts)
+          .transaction(),
+        signers: [admin, payer],
+      });
+      log("Thaw accounts txId ", thawTxId);
+
+      for (const tokenAccount of tokenAccounts) {
+        const account = await getAccount(
+          provider.connection,
+          tokenAccount,
+          "finalized",
+          TOKEN_2022_PROGRAM_ID
+        );
+        assert.isFalse(account.isFrozen);
+      }
+
+      const freezeTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .freezeAccounts(2)
+          .accounts(accounts)
+          .remainingAccounts(remainingAccounts)
+          .transaction(),
+        signers: [admin, payer],
+      });
+      log("Freeze accounts txId ", freezeTxId);
+
+      const record = await program.account.freezeRecord.fetch(
+        remainingAccounts[1].pubkey
+      );
+      assert.isTrue(record.frozen);
+      assert.equal(2, record.reasonCode);
+    })
+  );
+});
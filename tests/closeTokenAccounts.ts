@@ -0,0 +1,297 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenExtension } from "../target/types/token_extension";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  createAccount,
+  createAssociatedTokenAccountIdempotent,
+  createEnableCpiGuardInstruction,
+  createInitializeAccountInstruction,
+  createMint,
+  getAccount,
+  getAccountLen,
+  getMintLen,
+  getTransferFeeAmount,
+  mintTo,
+  transferCheckedWithFee,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  assert,
+  fetchAdminKeypair,
+  fetchPayerKeypair,
+  fetchReceiverKeypair,
+  initializeProgramConfig,
+  runTest,
+  sendAndConfirmTransaction,
+} from "./utils";
+import Debug from "debug";
+
+const log = Debug("log: closeTokenAccounts");
+
+describe("✅ tokenExtension: close token accounts", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
+
+  it(
+    "harvest and close empty accounts, skip the others",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      const receiver = fetchReceiverKeypair();
+
+      await airdrop(provider, payer.publicKey, receiver.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+      log("Mint", mint.publicKey.toBase58());
+
+      const decimals = 2;
+      const feeBasisPoints = 100;
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .transferFeeConfig(
+            new anchor.BN(getMintLen([ExtensionType.TransferFeeConfig])),
+            admin.publicKey,
+            admin.publicKey,
+            feeBasisPoints,
+            new anchor.BN(10_000)
+          )
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            payer: payer.publicKey,
+            allMintRole: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .transaction(),
+        signers: [admin, mint, payer],
+      });
+
+      const [adminATA, receiverATA] = await Promise.all(
+        [admin, receiver].map((wallet) =>
+          createAssociatedTokenAccountIdempotent(
+            provider.connection,
+            payer,
+            mint.publicKey,
+            wallet.publicKey,
+            { commitment: "finalized", skipPreflight: true },
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      );
+
+      const receiverFunded = await createAccount(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        receiver.publicKey,
+        anchor.web3.Keypair.generate(),
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      for (const tokenAccount of [adminATA, receiverFunded]) {
+        await mintTo(
+          provider.connection,
+          payer,
+          mint.publicKey,
+          tokenAccount,
+          admin,
+          10_000,
+          [],
+          { commitment: "finalized", skipPreflight: true },
+          TOKEN_2022_PROGRAM_ID
+        );
+      }
+
+      // receiverATA keeps the withheld fee once its balance is sent back.
+      await transferCheckedWithFee(
+        provider.connection,
+        payer,
+        adminATA,
+        mint.publicKey,
+        receiverATA,
+        admin,
+        BigInt(10_000),
+        decimals,
+        BigInt(100),
+        [],
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await transferCheckedWithFee(
+        provider.connection,
+        payer,
+        receiverATA,
+        mint.publicKey,
+        adminATA,
+        receiver,
+        BigInt(9_900),
+        decimals,
+        BigInt(99),
+        [],
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const closeTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .closeTokenAccounts()
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            owner: receiver.publicKey,
+            destination: receiver.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .remainingAccounts(
+            [receiverATA, receiverFunded, adminATA].map((pubkey) => ({
+              pubkey,
+              isSigner: false,
+              isWritable: true,
+            }))
+          )
+          .transaction(),
+        signers: [receiver],
+      });
+      log("Close token accounts txId ", closeTxId);
+
+      const [closed, funded, notOwned] =
+        await provider.connection.getMultipleAccountsInfo(
+          [receiverATA, receiverFunded, adminATA],
+          "finalized"
+        );
+      assert.isNull(closed);
+      assert.isNotNull(funded);
+      assert.isNotNull(notOwned);
+
+      // adminATA stays open with its balance but the fee it withheld is harvested.
+      const adminAccount = await getAccount(
+        provider.connection,
+        adminATA,
+        "finalized",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(
+        getTransferFeeAmount(adminAccount).withheldAmount,
+        BigInt(0)
+      );
+    })
+  );
+
+  it(
+    "skip accounts whose CPI Guard blocks closing to another destination",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      const receiver = fetchReceiverKeypair();
+
+      await airdrop(provider, payer.publicKey, receiver.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = await createMint(
+        provider.connection,
+        payer,
+        admin.publicKey,
+        null,
+        0,
+        anchor.web3.Keypair.generate(),
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+      log("Mint", mint.toBase58());
+
+      const guarded = anchor.web3.Keypair.generate();
+      const accountLen = getAccountLen([ExtensionType.CpiGuard]);
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: new anchor.web3.Transaction().add(
+          anchor.web3.SystemProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            newAccountPubkey: guarded.publicKey,
+            space: accountLen,
+            lamports:
+              await provider.connection.getMinimumBalanceForRentExemption(
+                accountLen
+              ),
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializeAccountInstruction(
+            guarded.publicKey,
+            mint,
+            receiver.publicKey,
+            TOKEN_2022_PROGRAM_ID
+          ),
+          createEnableCpiGuardInstruction(
+            guarded.publicKey,
+            receiver.publicKey,
+            [],
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        signers: [payer, guarded, receiver],
+      });
+      log("Guarded token account", guarded.publicKey.toBase58());
+
+      const unguarded = await createAccount(
+        provider.connection,
+        payer,
+        mint,
+        receiver.publicKey,
+        anchor.web3.Keypair.generate(),
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      // The lamports go to the payer, not to the owner of the accounts.
+      const closeTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .closeTokenAccounts()
+          .accounts({
+            programConfig,
+            mint,
+            owner: receiver.publicKey,
+            destination: payer.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .remainingAccounts(
+            [guarded.publicKey, unguarded].map((pubkey) => ({
+              pubkey,
+              isSigner: false,
+              isWritable: true,
+            }))
+          )
+          .transaction(),
+        signers: [receiver],
+      });
+      log("Close token accounts txId ", closeTxId);
+
+      const [guardedInfo, unguardedInfo] =
+        await provider.connection.getMultipleAccountsInfo(
+          [guarded.publicKey, unguarded],
+          "finalized"
+        );
+      assert.isNotNull(guardedInfo);
+      assert.isNull(unguardedInfo);
+    })
+  );
+});
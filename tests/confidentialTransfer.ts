@@ -4,6 +4,8 @@ import { TokenExtension } from "../target/types/token_extension";
 import {
   TOKEN_2022_PROGRAM_ID,
   ExtensionType,
+  createAccount,
+  createMint,
   getAccountLen,
 } from "@solana/spl-token";
 import {
@@ -11,11 +13,78 @@ import {
   fetchAdminKeypair,
   fetchPayerKeypair,
   airdrop,
+  getTokenExtensionState,
+  expect,
+  initializeProgramConfig,
+  programError,
+  rejectedWith,
 } from "./utils";
 import Debug from "debug";
 
 const log = Debug("log: confidentialTransfer");
 
+const ZK_TOKEN_PROOF_PROGRAM_ID = new anchor.web3.PublicKey(
+  "ZkTokenProof1111111111111111111111111111111"
+);
+
+// Proofs only cover ElGamal keys and ciphertexts, not account addresses, so
+// they were generated once with solana-zk-token-sdk and are replayed here.
+
+// ElGamal pubkey of the withdraw withheld authority.
+const WITHDRAW_WITHHELD_ELGAMAL_PUBKEY = Array.from(
+  Buffer.from(
+    "16edc684317a13a2e2557dcc1bfb46f50e7097cf305d6d429af368b8d5ba1235",
+    "hex"
+  )
+);
+
+// Token-2022 ConfigureAccount with a zero decryptable balance, followed by the
+// pubkey validity proof of the token account ElGamal key.
+const CONFIGURE_ACCOUNT_DATA = Buffer.from(
+  "1b02b283bd7397568b3803c5a87ae58f58a60b0bf4f0795fd248999b998ff5bf" +
+    "e58295695bf2000001000000000001",
+  "hex"
+);
+const PUBKEY_VALIDITY_PROOF_DATA = Buffer.from(
+  "06b2bbe991fa311dd112f66d99b12bd7a9226766e769a2d380eb6f066134ee89" +
+    "30eaa17a77d7387442387491578c8198792dbcf38eec880f0422dfdc92c5e668" +
+    "358c2bb73e58ce9ca00e5ea48ba441ab393112945d1bf35c35a184780a69c9c9" +
+    "00",
+  "hex"
+);
+
+// Proof that the zero withheld amount of the mint and the ciphertext credited
+// to the token account encrypt the same amount, kept in a context account.
+const CIPHERTEXT_EQUALITY_PROOF_DATA = Buffer.from(
+  "0316edc684317a13a2e2557dcc1bfb46f50e7097cf305d6d429af368b8d5ba12" +
+    "35b2bbe991fa311dd112f66d99b12bd7a9226766e769a2d380eb6f066134ee89" +
+    "3000000000000000000000000000000000000000000000000000000000000000" +
+    "0000000000000000000000000000000000000000000000000000000000000000" +
+    "008636b7a65db8cee62de73d9811f7b8c5497631bd6c635bdffa518adf81c165" +
+    "0b4ec29b11a6cc12e9106db3393cb431eff095a76f49b503fed61e55b8b41e4d" +
+    "3978992ba312e4b91739400f7e91da354d5eda60ed1f35ee40646496ba8e379c" +
+    "4c123f45e1ba9830e6b7eaa564cd8ab3b89476895d275633fd2ae5d4de84e3e1" +
+    "49369f705fc9cc76aebab38f4c67ef827bd424e1d818a8055788d09e29b31db5" +
+    "7470232ce3d75e85acd251ae34b3dd8d0c09ef62603e7e9b9e4410a7786d2779" +
+    "027dbd75f9c22a060d9c465eec219abe44ccb88e0d7603ba7b1bbe9f6b38bcdd" +
+    "01fcc593c2e6ae79f1b3dd54ba18b7d409be65085e3e9e9b9817dbde825fd5c1" +
+    "0635643b95eb4eedc5c735826e131b2a347fe50f4e9393f7518f28868a7a499b" +
+    "0c",
+  "hex"
+);
+const CIPHERTEXT_EQUALITY_CONTEXT_LEN = 225;
+const CREDITED_CIPHERTEXT =
+  "hja3pl24zuYt5z2YEfe4xUl2Mb1sY1vf+lGK34HBZQtOwpsRpswS6RBtszk8tDHv8JWnb0m1A/7WHlW4tB5NOQ==";
+const NEW_DECRYPTABLE_BALANCE = [
+  163, 133, 28, 134, 95, 89, 29, 85, 7, 191, 8, 107, 39, 231, 10, 218, 25, 83,
+  246, 63, 69, 146, 98, 94, 107, 193, 51, 166, 232, 206, 199, 186, 182, 205,
+  190, 254,
+];
+
+// TransferFeeAmount, ConfidentialTransferAccount and
+// ConfidentialTransferFeeAmount hard coded as 545 bytes
+const CONFIDENTIAL_FEE_ACCOUNT_LEN = 545;
+
 describe("🚧🚧 token-extension: confidential transfer work in progress 🚧🚧", () => {
   // Configure the client to use the local cluster.
   const provider = anchor.AnchorProvider.env();
@@ -23,6 +92,100 @@ describe("🚧🚧 token-extension: confidential transfer work in progress 🚧
 
   const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
 
+  async function createConfidentialFeeMint(
+    admin: anchor.web3.Keypair,
+    payer: anchor.web3.Keypair,
+    programConfig: anchor.web3.PublicKey
+  ) {
+    const mint = anchor.web3.Keypair.generate();
+    log("Mint", mint.publicKey.toBase58());
+
+    await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: await program.methods
+        .initializeConfidentialTransferFeeMint(
+          new anchor.BN(480),
+          2,
+          100,
+          new anchor.BN(5000),
+          true,
+          null,
+          WITHDRAW_WITHHELD_ELGAMAL_PUBKEY
+        )
+        .accounts({
+          programConfig,
+          mint: mint.publicKey,
+          payer: payer.publicKey,
+          allMintRole: admin.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .transaction(),
+      signers: [admin, mint, payer],
+    });
+
+    return mint.publicKey;
+  }
+
+  // Creates a token account of the mint and configures it for confidential
+  // transfers, which adds the confidential withheld fee of the mint.
+  async function createConfiguredAccount(
+    mint: anchor.web3.PublicKey,
+    owner: anchor.web3.Keypair,
+    payer: anchor.web3.Keypair,
+    programConfig: anchor.web3.PublicKey
+  ) {
+    const tokenAccount = anchor.web3.Keypair.generate();
+    log("TokenAccount", tokenAccount.publicKey.toBase58());
+
+    const transaction = new anchor.web3.Transaction().add(
+      await program.methods
+        .initializeConfidentialAccount(
+          new anchor.BN(CONFIDENTIAL_FEE_ACCOUNT_LEN)
+        )
+        .accounts({
+          programConfig,
+          mint,
+          tokenAccount: tokenAccount.publicKey,
+          payer: payer.publicKey,
+          owner: owner.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .instruction(),
+      new anchor.web3.TransactionInstruction({
+        programId: TOKEN_2022_PROGRAM_ID,
+        keys: [
+          { pubkey: tokenAccount.publicKey, isSigner: false, isWritable: true },
+          { pubkey: mint, isSigner: false, isWritable: false },
+          {
+            pubkey: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+            isSigner: false,
+            isWritable: false,
+          },
+          { pubkey: owner.publicKey, isSigner: true, isWritable: false },
+        ],
+        data: CONFIGURE_ACCOUNT_DATA,
+      }),
+      new anchor.web3.TransactionInstruction({
+        programId: ZK_TOKEN_PROOF_PROGRAM_ID,
+        keys: [],
+        data: PUBKEY_VALIDITY_PROOF_DATA,
+      })
+    );
+
+    const txId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction,
+      signers: [owner, tokenAccount, payer],
+    });
+    log("TokenAccount configured id ", txId);
+
+    return tokenAccount.publicKey;
+  }
+
   it("intialize confidential", async () => {
     const admin = fetchAdminKeypair();
 
@@ -30,6 +193,8 @@ describe("🚧🚧 token-extension: confidential transfer work in progress 🚧
 
     await airdrop(provider, payer.publicKey);
 
+    const programConfig = await initializeProgramConfig(program);
+
     const mint = anchor.web3.Keypair.generate();
     log("Mint", mint.publicKey.toBase58());
 
@@ -44,8 +209,9 @@ describe("🚧🚧 token-extension: confidential transfer work in progress 🚧
     const mintTxId = await sendAndConfirmTransaction({
       connection: provider.connection,
       transaction: await program.methods
-        .initializeConfidentialMint(mintLen, 2)
+        .initializeConfidentialMint(mintLen, 2, true, null)
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           payer: payer.publicKey,
           allMintRole: admin.publicKey,
@@ -67,6 +233,7 @@ describe("🚧🚧 token-extension: confidential transfer work in progress 🚧
       transaction: await program.methods
         .initializeConfidentialAccount(accountLen)
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           tokenAccount: tokenAccount.publicKey,
           payer: payer.publicKey,
@@ -80,4 +247,389 @@ describe("🚧🚧 token-extension: confidential transfer work in progress 🚧
     });
     log("TokenAccount initialized id ", accTxId);
   });
+
+  it("approve confidential account of a manual approval mint", async () => {
+    const admin = fetchAdminKeypair();
+
+    const payer = fetchPayerKeypair();
+
+    await airdrop(provider, payer.publicKey);
+
+    const programConfig = await initializeProgramConfig(program);
+
+    const mint = anchor.web3.Keypair.generate();
+    log("Mint", mint.publicKey.toBase58());
+
+    const tokenAccount = anchor.web3.Keypair.generate();
+    log("TokenAccount", tokenAccount.publicKey.toBase58());
+
+    const mintTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: await program.methods
+        .initializeConfidentialMint(new anchor.BN(235), 2, false, null)
+        .accounts({
+          programConfig,
+          mint: mint.publicKey,
+          payer: payer.publicKey,
+          allMintRole: admin.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .transaction(),
+      signers: [admin, mint, payer],
+    });
+    log("Mint initialized with manual approval id ", mintTxId);
+
+    const accTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: await program.methods
+        .initializeConfidentialAccount(
+          new anchor.BN(
+            getAccountLen([ExtensionType.ConfidentialTransferAccount])
+          )
+        )
+        .accounts({
+          programConfig,
+          mint: mint.publicKey,
+          tokenAccount: tokenAccount.publicKey,
+          payer: payer.publicKey,
+          owner: admin.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .transaction(),
+      signers: [admin, tokenAccount, payer],
+    });
+    log("TokenAccount initialized id ", accTxId);
+
+    let approved = await getTokenExtensionState(
+      provider.connection,
+      tokenAccount.publicKey,
+      "confidentialTransferAccount",
+      "approved"
+    );
+    expect(approved).to.be.false;
+
+    const approveTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: await program.methods
+        .approveConfidentialAccount()
+        .accounts({
+          programConfig,
+          mint: mint.publicKey,
+          tokenAccount: tokenAccount.publicKey,
+          confidentialAuthority: admin.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        })
+        .transaction(),
+      signers: [admin],
+    });
+    log("TokenAccount approved id ", approveTxId);
+
+    approved = await getTokenExtensionState(
+      provider.connection,
+      tokenAccount.publicKey,
+      "confidentialTransferAccount",
+      "approved"
+    );
+    expect(approved).to.be.true;
+  });
+
+  it("store and update confidential mint auditor", async () => {
+    const admin = fetchAdminKeypair();
+
+    const payer = fetchPayerKeypair();
+
+    await airdrop(provider, payer.publicKey);
+
+    const programConfig = await initializeProgramConfig(program);
+
+    const mint = anchor.web3.Keypair.generate();
+    log("Mint", mint.publicKey.toBase58());
+
+    // Any valid ElGamal pubkey works as auditor, reuse the ristretto basepoint for testing.
+    const auditorElgamalPubkey = Buffer.from(
+      "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76",
+      "hex"
+    );
+
+    const mintTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: await program.methods
+        .initializeConfidentialMint(
+          new anchor.BN(235),
+          2,
+          false,
+          Array.from(auditorElgamalPubkey)
+        )
+        .accounts({
+          programConfig,
+          mint: mint.publicKey,
+          payer: payer.publicKey,
+          allMintRole: admin.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .transaction(),
+      signers: [admin, mint, payer],
+    });
+    log("Mint initialized with auditor id ", mintTxId);
+
+    // The parsed account shows the ElGamal pubkey base64 encoded.
+    const auditor = await getTokenExtensionState(
+      provider.connection,
+      mint.publicKey,
+      "confidentialTransferMint",
+      "auditorElgamalPubkey"
+    );
+    expect(auditor).to.equal(auditorElgamalPubkey.toString("base64"));
+
+    const updateTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: await program.methods
+        .updateConfidentialMint(true, null)
+        .accounts({
+          programConfig,
+          mint: mint.publicKey,
+          confidentialAuthority: admin.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        })
+        .transaction(),
+      signers: [admin],
+    });
+    log("Mint updated id ", updateTxId);
+
+    const [autoApprove, updatedAuditor] = await Promise.all(
+      ["autoApproveNewAccounts", "auditorElgamalPubkey"].map((field) =>
+        getTokenExtensionState(
+          provider.connection,
+          mint.publicKey,
+          "confidentialTransferMint",
+          field
+        )
+      )
+    );
+    expect(autoApprove).to.be.true;
+    expect(updatedAuditor).to.be.null;
+  });
+
+  it("initialize confidential transfer fee mint", async () => {
+    const admin = fetchAdminKeypair();
+
+    const payer = fetchPayerKeypair();
+
+    await airdrop(provider, payer.publicKey);
+
+    const programConfig = await initializeProgramConfig(program);
+
+    const mint = anchor.web3.Keypair.generate();
+    log("Mint", mint.publicKey.toBase58());
+
+    // TransferFeeConfig, ConfidentialTransferMint and ConfidentialTransferFeeConfig hard coded as 480 bytes
+    const mintLen = new anchor.BN(480);
+
+    const withdrawWithheldElgamalPubkey = Array.from(
+      Buffer.from(
+        "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76",
+        "hex"
+      )
+    );
+
+    const mintTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: await program.methods
+        .initializeConfidentialTransferFeeMint(
+          mintLen,
+          2,
+          100,
+          new anchor.BN(5000),
+          true,
+          null,
+          withdrawWithheldElgamalPubkey
+        )
+        .accounts({
+          programConfig,
+          mint: mint.publicKey,
+          payer: payer.publicKey,
+          allMintRole: admin.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .transaction(),
+      signers: [admin, mint, payer],
+    });
+    log("Confidential transfer fee mint initialized id ", mintTxId);
+
+    const harvestToMintEnabled = await getTokenExtensionState(
+      provider.connection,
+      mint.publicKey,
+      "confidentialTransferFeeConfig",
+      "harvestToMintEnabled"
+    );
+    expect(harvestToMintEnabled).to.be.true;
+  });
+
+  it("harvest confidential withheld fees to the mint", async () => {
+    const admin = fetchAdminKeypair();
+
+    const payer = fetchPayerKeypair();
+
+    await airdrop(provider, payer.publicKey);
+
+    const programConfig = await initializeProgramConfig(program);
+
+    const mint = await createConfidentialFeeMint(admin, payer, programConfig);
+
+    const configured = await createConfiguredAccount(
+      mint,
+      admin,
+      payer,
+      programConfig
+    );
+
+    const harvest = async (source: anchor.web3.PublicKey) =>
+      sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .harvestConfidentialWithheldToken()
+          .accounts({
+            programConfig,
+            mint,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            { pubkey: source, isSigner: false, isWritable: true },
+          ])
+          .transaction(),
+        signers: [payer],
+      });
+
+    const harvestTxId = await harvest(configured);
+    log("Harvested to mint id ", harvestTxId);
+
+    const otherMint = await createMint(
+      provider.connection,
+      payer,
+      admin.publicKey,
+      null,
+      2,
+      anchor.web3.Keypair.generate(),
+      { commitment: "finalized", skipPreflight: true },
+      TOKEN_2022_PROGRAM_ID
+    );
+    const otherMintAccount = await createAccount(
+      provider.connection,
+      payer,
+      otherMint,
+      admin.publicKey,
+      anchor.web3.Keypair.generate(),
+      { commitment: "finalized", skipPreflight: true },
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    log("Failed: source of another mint");
+    await rejectedWith(
+      harvest(otherMintAccount),
+      Error,
+      programError(program, "MintMismatch")
+    );
+
+    const unconfigured = await createAccount(
+      provider.connection,
+      payer,
+      mint,
+      admin.publicKey,
+      anchor.web3.Keypair.generate(),
+      { commitment: "finalized", skipPreflight: true },
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    log("Failed: source without confidential withheld fees");
+    await rejectedWith(
+      harvest(unconfigured),
+      Error,
+      programError(program, "ExtensionMissing")
+    );
+  });
+
+  it("withdraw confidential withheld fees from the mint", async () => {
+    const admin = fetchAdminKeypair();
+
+    const payer = fetchPayerKeypair();
+
+    await airdrop(provider, payer.publicKey);
+
+    const programConfig = await initializeProgramConfig(program);
+
+    const mint = await createConfidentialFeeMint(admin, payer, programConfig);
+
+    const destination = await createConfiguredAccount(
+      mint,
+      admin,
+      payer,
+      programConfig
+    );
+
+    const proofContext = anchor.web3.Keypair.generate();
+    log("Proof context", proofContext.publicKey.toBase58());
+
+    const proofTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.createAccount({
+          fromPubkey: payer.publicKey,
+          newAccountPubkey: proofContext.publicKey,
+          space: CIPHERTEXT_EQUALITY_CONTEXT_LEN,
+          lamports:
+            await provider.connection.getMinimumBalanceForRentExemption(
+              CIPHERTEXT_EQUALITY_CONTEXT_LEN
+            ),
+          programId: ZK_TOKEN_PROOF_PROGRAM_ID,
+        }),
+        new anchor.web3.TransactionInstruction({
+          programId: ZK_TOKEN_PROOF_PROGRAM_ID,
+          keys: [
+            {
+              pubkey: proofContext.publicKey,
+              isSigner: false,
+              isWritable: true,
+            },
+            { pubkey: payer.publicKey, isSigner: false, isWritable: false },
+          ],
+          data: CIPHERTEXT_EQUALITY_PROOF_DATA,
+        })
+      ),
+      signers: [payer, proofContext],
+    });
+    log("Equality proof verified id ", proofTxId);
+
+    const withdrawTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: await program.methods
+        .withdrawConfidentialWithheldMint(NEW_DECRYPTABLE_BALANCE)
+        .accounts({
+          programConfig,
+          mint,
+          destination,
+          proofContext: proofContext.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          authority: admin.publicKey,
+        })
+        .transaction(),
+      signers: [admin],
+    });
+    log("Withdrew withheld fees from mint id ", withdrawTxId);
+
+    const availableBalance = await getTokenExtensionState(
+      provider.connection,
+      destination,
+      "confidentialTransferAccount",
+      "availableBalance"
+    );
+    expect(availableBalance).to.equal(CREDITED_CIPHERTEXT);
+  });
 });
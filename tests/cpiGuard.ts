@@ -8,14 +8,19 @@ import {
   createMint,
   getAccountLen,
   createEnableCpiGuardInstruction,
+  createDisableCpiGuardInstruction,
+  getAccount,
+  getCpiGuard,
   mintTo,
   createAssociatedTokenAccountIdempotent,
 } from "@solana/spl-token";
 import {
   airdrop,
+  assert,
   fetchAdminKeypair,
   fetchPayerKeypair,
   fetchReceiverKeypair,
+  initializeProgramConfig,
   rejectedWith,
   sendAndConfirmTransaction,
 } from "./utils";
@@ -45,6 +50,8 @@ describe("✅ token-extension: cpi guard enable", () => {
 
     await airdrop(provider, receiver.publicKey, payer.publicKey);
 
+    const programConfig = await initializeProgramConfig(program);
+
     const tokenAccount = anchor.web3.Keypair.generate();
     log("TokenAccount", tokenAccount.publicKey.toBase58());
 
@@ -71,6 +78,7 @@ describe("✅ token-extension: cpi guard enable", () => {
     const enableCpiGuardIx = await program.methods
       .initializeTokenAccountWith(accountLen)
       .accounts({
+        programConfig,
         mint: mint.publicKey,
         tokenAcc: tokenAccount.publicKey,
         payer: payer.publicKey,
@@ -163,6 +171,7 @@ describe("✅ token-extension: cpi guard enable", () => {
       transaction: await program.methods
         .transferToken(new anchor.BN(2 * 10 ** decimals), decimals)
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           fromAcc: associatedTokenAcc,
           toAcc: receiverATA,
@@ -193,6 +202,7 @@ describe("✅ token-extension: cpi guard enable", () => {
         transaction: await program.methods
           .transferToken(new anchor.BN(2 * 10 ** decimals), decimals)
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             fromAcc: tokenAccount.publicKey,
             toAcc: receiverATA,
@@ -208,4 +218,167 @@ describe("✅ token-extension: cpi guard enable", () => {
       Error
     );
   });
+
+  it("enable and disable cpi guard on an existing account", async () => {
+    const admin = fetchAdminKeypair();
+
+    const payer = fetchPayerKeypair();
+
+    const receiver = fetchReceiverKeypair();
+
+    await airdrop(provider, receiver.publicKey, payer.publicKey);
+
+    const programConfig = await initializeProgramConfig(program);
+
+    const mint = anchor.web3.Keypair.generate();
+    const decimals = 2;
+
+    await createMint(
+      provider.connection,
+      admin,
+      admin.publicKey,
+      admin.publicKey,
+      decimals,
+      mint,
+      { commitment: "finalized", skipPreflight: true },
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const [adminATA, receiverATA] = await Promise.all(
+      [admin, receiver].map((wallet) =>
+        createAssociatedTokenAccountIdempotent(
+          provider.connection,
+          payer,
+          mint.publicKey,
+          wallet.publicKey,
+          { commitment: "finalized", skipPreflight: true },
+          TOKEN_2022_PROGRAM_ID
+        )
+      )
+    );
+
+    await mintTo(
+      provider.connection,
+      admin,
+      mint.publicKey,
+      adminATA,
+      admin.publicKey,
+      20 * 10 ** decimals,
+      [],
+      { commitment: "finalized", skipPreflight: true },
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const toggleAccounts = {
+      programConfig,
+      tokenAccount: adminATA,
+      owner: admin.publicKey,
+      payer: payer.publicKey,
+      instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      token2022Program: TOKEN_2022_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    };
+
+    log("Failed: enable cpi guard without the Token-2022 instruction");
+    await rejectedWith(
+      sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .enableCpiGuard()
+          .accounts(toggleAccounts)
+          .transaction(),
+        signers: [admin, payer],
+      }),
+      Error
+    );
+
+    const enableTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: (
+        await program.methods
+          .enableCpiGuard()
+          .accounts(toggleAccounts)
+          .transaction()
+      ).add(
+        createEnableCpiGuardInstruction(
+          adminATA,
+          admin.publicKey,
+          [],
+          TOKEN_2022_PROGRAM_ID
+        )
+      ),
+      signers: [admin, payer],
+    });
+    log("Enable cpi guard txId ", enableTxId);
+
+    let account = await getAccount(
+      provider.connection,
+      adminATA,
+      "finalized",
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert.isTrue(getCpiGuard(account).lockCpi);
+
+    const transferAccounts = {
+      programConfig,
+      mint: mint.publicKey,
+      fromAcc: adminATA,
+      toAcc: receiverATA,
+      token2022Program: TOKEN_2022_PROGRAM_ID,
+      authority: admin.publicKey,
+      opaque: opaqueProgram.programId,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      badWallet: receiver.publicKey,
+    };
+
+    log("Failed: opaque transfer from cpi guarded account");
+    await rejectedWith(
+      sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .transferToken(new anchor.BN(2 * 10 ** decimals), decimals)
+          .accounts(transferAccounts)
+          .transaction(),
+        signers: [admin],
+      }),
+      Error
+    );
+
+    const disableTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: (
+        await program.methods
+          .disableCpiGuard()
+          .accounts(toggleAccounts)
+          .transaction()
+      ).add(
+        createDisableCpiGuardInstruction(
+          adminATA,
+          admin.publicKey,
+          [],
+          TOKEN_2022_PROGRAM_ID
+        )
+      ),
+      signers: [admin, payer],
+    });
+    log("Disable cpi guard txId ", disableTxId);
+
+    account = await getAccount(
+      provider.connection,
+      adminATA,
+      "finalized",
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert.isFalse(getCpiGuard(account).lockCpi);
+
+    const transferTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: await program.methods
+        .transferToken(new anchor.BN(2 * 10 ** decimals), decimals)
+        .accounts(transferAccounts)
+        .transaction(),
+      signers: [admin],
+    });
+    log("Opaque transfer after disabling cpi guard txId ", transferTxId);
+  });
 });
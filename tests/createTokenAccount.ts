@@ -0,0 +1,159 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenExtension } from "../target/types/token_extension";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  createEnableCpiGuardInstruction,
+  createMint,
+  getAccount,
+  getCpiGuard,
+  getImmutableOwner,
+  getMemoTransfer,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  assert,
+  fetchAdminKeypair,
+  fetchPayerKeypair,
+  fetchReceiverKeypair,
+  initializeProgramConfig,
+  programError,
+  rejectedWith,
+  runTest,
+  sendAndConfirmTransaction,
+} from "./utils";
+import Debug from "debug";
+
+const log = Debug("log: createTokenAccount");
+
+describe("✅ tokenExtension: create token account with extensions", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
+
+  it(
+    "immutable owner, memo transfer, cpi guard and a delegate in one account",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      const receiver = fetchReceiverKeypair();
+
+      await airdrop(provider, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+
+      await createMint(
+        provider.connection,
+        payer,
+        admin.publicKey,
+        null,
+        2,
+        mint,
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+      log("Mint", mint.publicKey.toBase58());
+
+      const tokenAccount = anchor.web3.Keypair.generate();
+
+      const txId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: (
+          await program.methods
+            .createTokenAccount({
+              immutableOwner: true,
+              memoTransfer: true,
+              cpiGuard: true,
+              delegate: receiver.publicKey,
+              delegatedAmount: new anchor.BN(500),
+            })
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              tokenAccount: tokenAccount.publicKey,
+              owner: admin.publicKey,
+              delegate: receiver.publicKey,
+              payer: payer.publicKey,
+              instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+              systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .transaction()
+        ).add(
+          createEnableCpiGuardInstruction(
+            tokenAccount.publicKey,
+            admin.publicKey,
+            [],
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        signers: [payer, tokenAccount, admin],
+      });
+      log("Create token account txId ", txId);
+
+      const account = await getAccount(
+        provider.connection,
+        tokenAccount.publicKey,
+        "finalized",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.isNotNull(getImmutableOwner(account));
+      assert.isTrue(getMemoTransfer(account).requireIncomingTransferMemos);
+      assert.isTrue(getCpiGuard(account).lockCpi);
+      assert.isTrue(account.delegate.equals(receiver.publicKey));
+      assert.equal(account.delegatedAmount, BigInt(500));
+
+      const withoutDelegate = async (
+        delegate: anchor.web3.PublicKey | null,
+        delegatedAmount: number
+      ) => {
+        const tokenAccount = anchor.web3.Keypair.generate();
+
+        return sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .createTokenAccount({
+              immutableOwner: false,
+              memoTransfer: true,
+              cpiGuard: false,
+              delegate: null,
+              delegatedAmount: new anchor.BN(delegatedAmount),
+            })
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              tokenAccount: tokenAccount.publicKey,
+              owner: admin.publicKey,
+              delegate,
+              payer: payer.publicKey,
+              instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+              systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .transaction(),
+          signers: [payer, tokenAccount, admin],
+        });
+      };
+
+      log("Failed: delegate account without a requested delegate");
+      await rejectedWith(
+        withoutDelegate(receiver.publicKey, 0),
+        Error,
+        programError(program, "InvalidDelegate")
+      );
+
+      log("Failed: delegated amount without a delegate");
+      await rejectedWith(
+        withoutDelegate(null, 500),
+        Error,
+        programError(program, "InvalidDelegate")
+      );
+    })
+  );
+});
@@ -5,7 +5,6 @@ import {
   TOKEN_2022_PROGRAM_ID,
   ExtensionType,
   getMintLen,
-  AccountState,
 } from "@solana/spl-token";
 import {
   airdrop,
@@ -13,6 +12,8 @@ import {
   fetchAdminKeypair,
   fetchPayerKeypair,
   getTokenExtensionState,
+  initializeProgramConfig,
+  rejectedWith,
   runTest,
   sendAndConfirmTransaction,
 } from "./utils";
@@ -36,6 +37,8 @@ describe("✅ tokenExtension: default account state", () => {
 
       await airdrop(provider, payer.publicKey);
 
+      const programConfig = await initializeProgramConfig(program);
+
       const mint = anchor.web3.Keypair.generate();
       log("Mint", mint.publicKey.toBase58());
 
@@ -43,9 +46,11 @@ describe("✅ tokenExtension: default account state", () => {
         connection: provider.connection,
         transaction: await program.methods
           .defaultAccountState(
-            new anchor.BN(getMintLen([ExtensionType.DefaultAccountState]))
+            new anchor.BN(getMintLen([ExtensionType.DefaultAccountState])),
+            { frozen: {} }
           )
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             payer: payer.publicKey,
             allMintRole: admin.publicKey,
@@ -68,16 +73,33 @@ describe("✅ tokenExtension: default account state", () => {
 
       assert.equal("frozen", result);
 
+      const updateIx = await program.methods
+        .updateDefaultAccountState({ initialized: {} })
+        .accounts({
+          programConfig,
+          mint: mint.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          freezeAuth: admin.publicKey,
+        })
+        .instruction();
+
+      log("Failed: freeze authority did not sign");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: new anchor.web3.Transaction().add(updateIx),
+          signers: [payer],
+        }),
+        Error
+      );
+
+      // freezeAuth is a signer or a multisig, so mark the signer explicitly
+      updateIx.keys.find((key) => key.pubkey.equals(admin.publicKey)).isSigner =
+        true;
+
       await sendAndConfirmTransaction({
         connection: provider.connection,
-        transaction: await program.methods
-          .updateDefaultAccountState(AccountState.Initialized)
-          .accounts({
-            mint: mint.publicKey,
-            token2022Program: TOKEN_2022_PROGRAM_ID,
-            freezeAuth: admin.publicKey,
-          })
-          .transaction(),
+        transaction: new anchor.web3.Transaction().add(updateIx),
         signers: [admin],
       });
 
@@ -0,0 +1,281 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenExtension } from "../target/types/token_extension";
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotent,
+  getAccount,
+  getMintLen,
+  mintTo,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  expect,
+  fetchAdminKeypair,
+  fetchPayerKeypair,
+  fetchReceiverKeypair,
+  initializeProgramConfig,
+  MEMO_PROGRAM_ID,
+  programError,
+  rejectedWith,
+  runTest,
+  sendAndConfirmTransaction,
+} from "./utils";
+import Debug from "debug";
+
+const log = Debug("log: delegateGovernance");
+
+describe("✅ token-extension: multisig governed permanent delegate", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
+
+  it(
+    "clawback after 2 of 2 approvals and cancel a burn proposal",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const receiver = fetchReceiverKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      await airdrop(provider, receiver.publicKey, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+      log("Mint", mint.publicKey.toBase58());
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .permanentDelegate(
+            new anchor.BN(getMintLen([ExtensionType.PermanentDelegate]))
+          )
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            payer: payer.publicKey,
+            allMintRole: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .transaction(),
+        signers: [admin, mint, payer],
+      });
+
+      const [adminATA, receiverATA] = await Promise.all(
+        [admin, receiver].map((wallet) =>
+          createAssociatedTokenAccountIdempotent(
+            provider.connection,
+            payer,
+            mint.publicKey,
+            wallet.publicKey,
+            { commitment: "finalized", skipPreflight: true },
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      );
+
+      await mintTo(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        receiverATA,
+        admin,
+        100,
+        [],
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const [delegateGovernance] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("delegate-governance"), mint.publicKey.toBuffer()],
+        program.programId
+      );
+
+      const govTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .initializeDelegateGovernance([admin.publicKey, payer.publicKey], 2)
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            delegateGovernance,
+            payer: payer.publicKey,
+            permanentDelegate: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .transaction(),
+        signers: [admin, payer],
+      });
+      log("Delegate governance initialized txId ", govTxId);
+
+      const proposalAddress = (id: number) =>
+        anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("delegate-proposal"),
+            delegateGovernance.toBuffer(),
+            new anchor.BN(id).toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId
+        )[0];
+
+      const clawbackProposal = proposalAddress(0);
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .proposeDelegateAction(
+            { clawback: {} },
+            new anchor.BN(30),
+            "Fraud case #7",
+            new anchor.BN(3600)
+          )
+          .accounts({
+            programConfig,
+            delegateGovernance,
+            proposal: clawbackProposal,
+            from: receiverATA,
+            treasury: adminATA,
+            proposer: admin.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .transaction(),
+        signers: [admin],
+      });
+
+      const executeAccounts = {
+        programConfig,
+        mint: mint.publicKey,
+        delegateGovernance,
+        proposal: clawbackProposal,
+        from: receiverATA,
+        treasury: adminATA,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        memoProgram: MEMO_PROGRAM_ID,
+      };
+
+      log("Failed: proposal below threshold");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .executeDelegateProposal()
+            .accounts(executeAccounts)
+            .transaction(),
+          signers: [payer],
+        }),
+        Error
+      );
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .approveDelegateProposal()
+          .accounts({
+            programConfig,
+            delegateGovernance,
+            proposal: clawbackProposal,
+            signer: payer.publicKey,
+          })
+          .transaction(),
+        signers: [payer],
+      });
+
+      const executeTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .executeDelegateProposal()
+          .accounts(executeAccounts)
+          .transaction(),
+        signers: [payer],
+      });
+      log("Clawback executed txId ", executeTxId);
+
+      // The proposal reason is written as a memo ahead of the transfer.
+      const executeTx = await provider.connection.getTransaction(executeTxId, {
+        commitment: "finalized",
+        maxSupportedTransactionVersion: 0,
+      });
+      expect(executeTx.meta.logMessages.join("\n")).to.include(
+        '"Fraud case #7"'
+      );
+
+      const receiverAccount = await getAccount(
+        provider.connection,
+        receiverATA,
+        "finalized",
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(receiverAccount.amount).to.equal(BigInt(70));
+
+      const burnProposal = proposalAddress(1);
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .proposeDelegateAction(
+            { burn: {} },
+            new anchor.BN(10),
+            "Duplicate issuance",
+            new anchor.BN(3600)
+          )
+          .accounts({
+            programConfig,
+            delegateGovernance,
+            proposal: burnProposal,
+            from: receiverATA,
+            treasury: null,
+            proposer: payer.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .transaction(),
+        signers: [payer],
+      });
+
+      log("Failed: cancel by a signer other than the proposer");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .cancelDelegateProposal()
+            .accounts({
+              programConfig,
+              delegateGovernance,
+              proposal: burnProposal,
+              signer: admin.publicKey,
+            })
+            .transaction(),
+          signers: [admin],
+        }),
+        Error,
+        programError(program, "Unauthorized")
+      );
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .cancelDelegateProposal()
+          .accounts({
+            programConfig,
+            delegateGovernance,
+            proposal: burnProposal,
+            signer: payer.publicKey,
+          })
+          .transaction(),
+        signers: [payer],
+      });
+
+      const proposal = await program.account.delegateProposal.fetch(
+        burnProposal
+      );
+      expect(proposal.status).to.deep.equal({ cancelled: {} });
+    })
+  );
+});
@@ -0,0 +1,139 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenExtension } from "../target/types/token_extension";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  createAssociatedTokenAccountIdempotent,
+  getMintLen,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  assert,
+  fetchAdminKeypair,
+  fetchEvents,
+  fetchPayerKeypair,
+  fetchReceiverKeypair,
+  initializeProgramConfig,
+  runTest,
+  sendAndConfirmTransaction,
+} from "./utils";
+import Debug from "debug";
+
+const log = Debug("log: events");
+
+describe("✅ tokenExtension: events", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
+
+  it(
+    "mint creation, mint_to and transfer_to emit events with mint and slot",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      const receiver = fetchReceiverKeypair();
+
+      await airdrop(provider, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+      log("Mint", mint.publicKey.toBase58());
+
+      const createTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .transferFeeConfig(
+            new anchor.BN(getMintLen([ExtensionType.TransferFeeConfig])),
+            admin.publicKey,
+            admin.publicKey,
+            100,
+            new anchor.BN(10_000)
+          )
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            payer: payer.publicKey,
+            allMintRole: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .transaction(),
+        signers: [admin, mint, payer],
+      });
+
+      const [mintCreated] = await fetchEvents(program, createTxId);
+      assert.equal(mintCreated.name, "MintCreated");
+      assert.isTrue(mintCreated.data.mint.equals(mint.publicKey));
+      assert.deepEqual(mintCreated.data.extensions, [
+        ExtensionType.TransferFeeConfig,
+      ]);
+      assert.isTrue(mintCreated.data.mintAuthority.equals(admin.publicKey));
+
+      const [adminATA, receiverATA] = await Promise.all(
+        [admin, receiver].map((wallet) =>
+          createAssociatedTokenAccountIdempotent(
+            provider.connection,
+            payer,
+            mint.publicKey,
+            wallet.publicKey,
+            { commitment: "finalized", skipPreflight: true },
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      );
+
+      const mintTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .mintTo(new anchor.BN(10_000))
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            associatedToken: adminATA,
+            authority: admin.publicKey,
+          })
+          .transaction(),
+        signers: [admin],
+      });
+
+      const [tokensMinted] = await fetchEvents(program, mintTxId);
+      assert.equal(tokensMinted.name, "TokensMinted");
+      assert.isTrue(tokensMinted.data.to.equals(adminATA));
+      assert.equal(tokensMinted.data.amount.toNumber(), 10_000);
+
+      const transferTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .transferTo(new anchor.BN(1_000), new anchor.BN(10))
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            fromAcc: adminATA,
+            toAcc: receiverATA,
+            authority: admin.publicKey,
+          })
+          .transaction(),
+        signers: [admin],
+      });
+
+      const [transferExecuted] = await fetchEvents(program, transferTxId);
+      assert.equal(transferExecuted.name, "TransferExecuted");
+      assert.isTrue(transferExecuted.data.mint.equals(mint.publicKey));
+      assert.equal(transferExecuted.data.amount.toNumber(), 1_000);
+      assert.equal(transferExecuted.data.fee.toNumber(), 10);
+      assert.isAbove(
+        transferExecuted.data.slot.toNumber(),
+        tokensMinted.data.slot.toNumber()
+      );
+    })
+  );
+});
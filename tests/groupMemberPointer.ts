@@ -10,6 +10,7 @@ import {
   airdrop,
   fetchAdminKeypair,
   fetchPayerKeypair,
+  initializeProgramConfig,
   sendAndConfirmTransaction,
 } from "./utils";
 import Debug from "debug";
@@ -30,6 +31,8 @@ describe("🚨 token-extension: intialize group and member pointer support issue
 
     await airdrop(provider, payer.publicKey);
 
+    const programConfig = await initializeProgramConfig(program);
+
     const group = anchor.web3.Keypair.generate();
     log("Group", group.publicKey.toBase58());
 
@@ -45,6 +48,7 @@ describe("🚨 token-extension: intialize group and member pointer support issue
       transaction: await program.methods
         .initializeGroupPointer(mintLen, decimals, maxSize)
         .accounts({
+          programConfig,
           mint: group.publicKey,
           payer: payer.publicKey,
           allMintRole: admin.publicKey,
@@ -66,6 +70,7 @@ describe("🚨 token-extension: intialize group and member pointer support issue
       transaction: await program.methods
         .initializeMemberPointer(mintLenGMP, decimals)
         .accounts({
+          programConfig,
           member: member.publicKey,
           group: group.publicKey,
           payer: admin.publicKey,
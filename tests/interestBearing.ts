@@ -11,8 +11,11 @@ import {
 } from "@solana/spl-token";
 import {
   airdrop,
+  expect,
   fetchAdminKeypair,
   fetchPayerKeypair,
+  getTokenExtensionState,
+  initializeProgramConfig,
   runTest,
   sendAndConfirmTransaction,
 } from "./utils";
@@ -36,6 +39,8 @@ describe("✅ tokenExtension: interest bearing token", () => {
 
       await airdrop(provider, payer.publicKey);
 
+      const programConfig = await initializeProgramConfig(program);
+
       const mint = anchor.web3.Keypair.generate();
       log("Mint", mint.publicKey.toBase58());
 
@@ -47,6 +52,7 @@ describe("✅ tokenExtension: interest bearing token", () => {
             5
           )
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             token2022Program: TOKEN_2022_PROGRAM_ID,
             payer: payer.publicKey,
@@ -106,6 +112,58 @@ describe("✅ tokenExtension: interest bearing token", () => {
         .view();
 
       log("AmountUi ", amountTx);
+
+      const rawAmount = await program.methods
+        .uiAmountToAmount(amountTx)
+        .accounts({
+          mint: mint.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        })
+        .view();
+
+      log("Raw amount ", rawAmount.toString());
+      expect(rawAmount.toNumber()).to.be.closeTo(Number(ata.amount), 1);
+
+      const updateTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .updateRate(10)
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            rateAuthority: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .transaction(),
+        signers: [admin],
+      });
+      log("Update rate txId ", updateTxId);
+
+      const currentRate = await getTokenExtensionState(
+        provider.connection,
+        mint.publicKey,
+        "interestBearingConfig",
+        "currentRate"
+      );
+      expect(currentRate).to.equal(10);
+
+      const now = await program.methods
+        .projectInterest(new anchor.BN(ata.amount.toString()), null)
+        .accounts({ mint: mint.publicKey })
+        .view();
+      log("Projection now ", now);
+      expect(now.effectiveRate).to.equal(10);
+
+      const oneYear = 60 * 60 * 24 * 365;
+      const later = await program.methods
+        .projectInterest(
+          new anchor.BN(ata.amount.toString()),
+          now.unixTimestamp.add(new anchor.BN(oneYear))
+        )
+        .accounts({ mint: mint.publicKey })
+        .view();
+      log("Projection in a year ", later);
+      expect(later.accruedAmount.gt(now.accruedAmount)).to.be.true;
     })
   );
 });
@@ -0,0 +1,171 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenExtension } from "../target/types/token_extension";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  getMintLen,
+  getAccount,
+  createAssociatedTokenAccountIdempotent,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  assert,
+  fetchAdminKeypair,
+  fetchPayerKeypair,
+  fetchReceiverKeypair,
+  initializeProgramConfig,
+  runTest,
+  sendAndConfirmTransaction,
+} from "./utils";
+import Debug from "debug";
+
+const log = Debug("log: kycOnboarding");
+
+describe("✅ tokenExtension: kyc onboarding", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
+
+  it(
+    "request, approve and revoke access to a default frozen account",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      const receiver = fetchReceiverKeypair();
+
+      await airdrop(provider, payer.publicKey, receiver.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+      log("Mint", mint.publicKey.toBase58());
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .defaultAccountState(
+            new anchor.BN(getMintLen([ExtensionType.DefaultAccountState])),
+            { frozen: {} }
+          )
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            payer: payer.publicKey,
+            allMintRole: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .transaction(),
+        signers: [admin, mint, payer],
+      });
+
+      const [kycConfig] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("kyc-config"), mint.publicKey.toBuffer()],
+        program.programId
+      );
+
+      const kycTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .initializeKyc()
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            kycConfig,
+            payer: payer.publicKey,
+            freezeAuthority: admin.publicKey,
+            complianceOfficer: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .transaction(),
+        signers: [admin, payer],
+      });
+      log("Kyc initialized txId ", kycTxId);
+
+      const receiverATA = await createAssociatedTokenAccountIdempotent(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        receiver.publicKey,
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+      log("Receiver ATA ", receiverATA.toBase58());
+
+      const [accessRequest] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("access-request"), receiverATA.toBuffer()],
+        program.programId
+      );
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .requestAccess()
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            tokenAccount: receiverATA,
+            accessRequest,
+            wallet: receiver.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .transaction(),
+        signers: [receiver],
+      });
+
+      const reviewAccounts = {
+        programConfig,
+        mint: mint.publicKey,
+        tokenAccount: receiverATA,
+        kycConfig,
+        accessRequest,
+        complianceOfficer: admin.publicKey,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      };
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .approveAccess()
+          .accounts(reviewAccounts)
+          .transaction(),
+        signers: [admin],
+      });
+
+      let account = await getAccount(
+        provider.connection,
+        receiverATA,
+        "finalized",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.isFalse(account.isFrozen);
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .revokeAccess()
+          .accounts(reviewAccounts)
+          .transaction(),
+        signers: [admin],
+      });
+
+      account = await getAccount(
+        provider.connection,
+        receiverATA,
+        "finalized",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.isTrue(account.isFrozen);
+
+      const request = await program.account.accessRequest.fetch(accessRequest);
+      assert.deepEqual(request.status, { revoked: {} });
+    })
+  );
+});
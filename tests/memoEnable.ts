@@ -16,6 +16,9 @@ import {
   fetchReceiverKeypair,
   fetchPayerKeypair,
   airdrop,
+  assert,
+  rejectedWith,
+  initializeProgramConfig,
 } from "./utils";
 import Debug from "debug";
 
@@ -37,6 +40,8 @@ describe("✅ token-extension: memo enable transfer", () => {
 
     await airdrop(provider, payer.publicKey);
 
+    const programConfig = await initializeProgramConfig(program);
+
     const tokenAccount = anchor.web3.Keypair.generate();
     log("Token account", tokenAccount.publicKey.toBase58());
 
@@ -90,6 +95,7 @@ describe("✅ token-extension: memo enable transfer", () => {
       transaction: await program.methods
         .enableMemo(accountLen)
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           tokenAccount: tokenAccount.publicKey,
           payer: payer.publicKey,
@@ -104,22 +110,99 @@ describe("✅ token-extension: memo enable transfer", () => {
 
     log("Memo enable tx id ", memoId);
 
+    const memoTransferAccounts = {
+      programConfig,
+      mint: mint.publicKey,
+      fromAcc: associatedTokenAcc,
+      toAcc: tokenAccount.publicKey,
+      authority: admin.publicKey,
+      memoProgram: MEMO_PROGRAM_ID,
+      token2022Program: TOKEN_2022_PROGRAM_ID,
+    };
+
     const mTid = await sendAndConfirmTransaction({
       connection: provider.connection,
       transaction: await program.methods
-        .memoTransfer(new anchor.BN(5 * 10 ** decimals), decimals)
-        .accounts({
-          mint: mint.publicKey,
-          fromAcc: associatedTokenAcc,
-          toAcc: tokenAccount.publicKey,
-          authority: admin.publicKey,
-          memoProgram: MEMO_PROGRAM_ID,
-          token2022Program: TOKEN_2022_PROGRAM_ID,
+        .memoTransfer(new anchor.BN(5 * 10 ** decimals), decimals, {
+          text: { text: "Testing memo string" },
         })
+        .accounts(memoTransferAccounts)
         .transaction(),
       signers: [admin],
     });
 
     log("Memo transfer tx id ", mTid);
+
+    const refTid = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: await program.methods
+        .memoTransfer(new anchor.BN(5 * 10 ** decimals), decimals, {
+          reference: { invoiceId: "INV-2024-001", payerReference: "acme_corp" },
+        })
+        .accounts(memoTransferAccounts)
+        .transaction(),
+      signers: [admin],
+    });
+
+    log("Reference memo transfer tx id ", refTid);
+
+    log("Failed: reference with invalid characters");
+    await rejectedWith(
+      sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .memoTransfer(new anchor.BN(5 * 10 ** decimals), decimals, {
+            reference: { invoiceId: 'INV"1', payerReference: "acme_corp" },
+          })
+          .accounts(memoTransferAccounts)
+          .transaction(),
+        signers: [admin],
+      }),
+      Error
+    );
+
+    log("Failed: memo longer than the limit");
+    await rejectedWith(
+      sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .memoTransfer(new anchor.BN(5 * 10 ** decimals), decimals, {
+            text: { text: "x".repeat(257) },
+          })
+          .accounts(memoTransferAccounts)
+          .transaction(),
+        signers: [admin],
+      }),
+      Error
+    );
+
+    let requirements = await program.methods
+      .transferRequirements()
+      .accounts({ tokenAccount: tokenAccount.publicKey })
+      .view();
+    assert.isTrue(requirements.memoRequired);
+    assert.isFalse(requirements.cpiGuardEnabled);
+
+    const disableTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: await program.methods
+        .disableMemo()
+        .accounts({
+          programConfig,
+          tokenAccount: tokenAccount.publicKey,
+          owner: receiver.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        })
+        .transaction(),
+      signers: [payer, receiver],
+    });
+
+    log("Memo disable tx id ", disableTxId);
+
+    requirements = await program.methods
+      .transferRequirements()
+      .accounts({ tokenAccount: tokenAccount.publicKey })
+      .view();
+    assert.isFalse(requirements.memoRequired);
   });
 });
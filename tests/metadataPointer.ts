@@ -14,6 +14,7 @@ import {
   fetchAdminKeypair,
   keypairFromFile,
   fetchPayerKeypair,
+  initializeProgramConfig,
 } from "./utils";
 import Debug from "debug";
 
@@ -33,6 +34,8 @@ describe("✅ token-extension: metadata pointer and token metadata", () => {
 
     await airdrop(provider, payer.publicKey);
 
+    const programConfig = await initializeProgramConfig(program);
+
     const fakeUpdateAuth = keypairFromFile(
       path.join(__dirname, "../keypairs/receiver.json")
     );
@@ -57,6 +60,7 @@ describe("✅ token-extension: metadata pointer and token metadata", () => {
           "http://my.token"
         )
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           payer: payer.publicKey,
           allMintRole: admin.publicKey,
@@ -76,6 +80,7 @@ describe("✅ token-extension: metadata pointer and token metadata", () => {
       transaction: await program.methods
         .updateMetadataField({ name: {} }, newName)
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           payer: payer.publicKey,
           updateAuthority: admin.publicKey, // Update token metadata using correct updateAuthority
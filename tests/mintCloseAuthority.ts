@@ -4,12 +4,17 @@ import { TokenExtension } from "../target/types/token_extension";
 import {
   TOKEN_2022_PROGRAM_ID,
   ExtensionType,
+  burn,
+  createAssociatedTokenAccountIdempotent,
   getMintLen,
+  mintTo,
 } from "@solana/spl-token";
 import {
   airdrop,
   fetchAdminKeypair,
   fetchPayerKeypair,
+  initializeProgramConfig,
+  rejectedWith,
   runTest,
   sendAndConfirmTransaction,
 } from "./utils";
@@ -34,6 +39,8 @@ describe("✅ token-extension: mintCloseAuthority and closeMintAccount", () => {
 
       await airdrop(provider, payer.publicKey);
 
+      const programConfig = await initializeProgramConfig(program);
+
       const mint = anchor.web3.Keypair.generate();
       log("Mint", mint.publicKey.toBase58());
 
@@ -45,6 +52,7 @@ describe("✅ token-extension: mintCloseAuthority and closeMintAccount", () => {
             new anchor.BN(getMintLen([ExtensionType.MintCloseAuthority]))
           )
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             payer: payer.publicKey,
             allMintRole: admin.publicKey,
@@ -74,6 +82,7 @@ describe("✅ token-extension: mintCloseAuthority and closeMintAccount", () => {
         transaction: await program.methods
           .closeMintAccount()
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             destination: admin.publicKey,
             authority: admin.publicKey,
@@ -89,4 +98,118 @@ describe("✅ token-extension: mintCloseAuthority and closeMintAccount", () => {
       assert.isNull(deletedMintAcc.value);
     })
   );
+
+  it(
+    "safeCloseMint checks close authority and supply",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      await airdrop(provider, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+      log("Mint", mint.publicKey.toBase58());
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .mintCloseAuthority(
+            new anchor.BN(getMintLen([ExtensionType.MintCloseAuthority]))
+          )
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            payer: payer.publicKey,
+            allMintRole: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .transaction(),
+        signers: [admin, mint, payer],
+      });
+
+      const adminATA = await createAssociatedTokenAccountIdempotent(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        admin.publicKey,
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await mintTo(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        adminATA,
+        admin,
+        10,
+        [],
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const safeClose = (closeAuthority: anchor.web3.PublicKey) =>
+        program.methods
+          .safeCloseMint()
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            closeAuthority,
+            withdrawWithheldAuthority: null,
+            feeDestination: null,
+            destination: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .transaction();
+
+      log("Failed: mint supply is not zero");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await safeClose(admin.publicKey),
+          signers: [admin],
+        }),
+        Error
+      );
+
+      await burn(
+        provider.connection,
+        payer,
+        adminATA,
+        mint.publicKey,
+        admin,
+        10,
+        [],
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      log("Failed: signer is not the close authority");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await safeClose(payer.publicKey),
+          signers: [payer],
+        }),
+        Error
+      );
+
+      const closeTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await safeClose(admin.publicKey),
+        signers: [admin],
+      });
+      log("Safe close mint txId ", closeTxId);
+
+      const deletedMintAcc = await provider.connection.getParsedAccountInfo(
+        mint.publicKey
+      );
+      assert.isNull(deletedMintAcc.value);
+    })
+  );
 });
@@ -11,6 +11,7 @@ import {
   airdrop,
   fetchAdminKeypair,
   fetchPayerKeypair,
+  initializeProgramConfig,
   runTest,
   sendAndConfirmTransaction,
 } from "./utils";
@@ -34,6 +35,8 @@ describe("✅ tokenExtension: non transferable token", () => {
 
       await airdrop(provider, payer.publicKey);
 
+      const programConfig = await initializeProgramConfig(program);
+
       const mint = anchor.web3.Keypair.generate();
       log("Mint", mint.publicKey.toBase58());
 
@@ -53,6 +56,7 @@ describe("✅ tokenExtension: non transferable token", () => {
             )
           )
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             token2022Program: TOKEN_2022_PROGRAM_ID,
             account: account.publicKey,
@@ -0,0 +1,301 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenExtension } from "../target/types/token_extension";
+import { Opaque } from "./../target/types/opaque";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  createAccount,
+  createApproveInstruction,
+  createEnableCpiGuardInstruction,
+  createMint,
+  getAccount,
+  mintTo,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  assert,
+  fetchAdminKeypair,
+  fetchPayerKeypair,
+  fetchReceiverKeypair,
+  fulfilled,
+  initializeProgramConfig,
+  rejectedWith,
+  runTest,
+  sendAndConfirmTransaction,
+} from "./utils";
+import Debug from "debug";
+
+const log = Debug("log: opaqueCpiGuard");
+
+describe("✅ token-extension: cpi guard rules through the opaque program", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
+  const opaqueProgram = anchor.workspace.Opaque as Program<Opaque>;
+
+  it(
+    "guarded accounts reject every blocked cpi, unguarded accounts accept them",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      const receiver = fetchReceiverKeypair();
+
+      await airdrop(provider, receiver.publicKey, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+      const decimals = 2;
+
+      await createMint(
+        provider.connection,
+        admin,
+        admin.publicKey,
+        admin.publicKey,
+        decimals,
+        mint,
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+      log("Mint", mint.publicKey.toBase58());
+
+      const newAccount = (owner: anchor.web3.Keypair) =>
+        createAccount(
+          provider.connection,
+          payer,
+          mint.publicKey,
+          owner.publicKey,
+          anchor.web3.Keypair.generate(),
+          { commitment: "finalized", skipPreflight: true },
+          TOKEN_2022_PROGRAM_ID
+        );
+
+      const [guarded, unguarded, emptyGuarded, emptyUnguarded, receiverAcc] =
+        await Promise.all([
+          newAccount(admin),
+          newAccount(admin),
+          newAccount(admin),
+          newAccount(admin),
+          newAccount(receiver),
+        ]);
+
+      for (const tokenAccount of [guarded, emptyGuarded]) {
+        await sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: (
+            await program.methods
+              .enableCpiGuard()
+              .accounts({
+                programConfig,
+                tokenAccount,
+                owner: admin.publicKey,
+                payer: payer.publicKey,
+                instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+                token2022Program: TOKEN_2022_PROGRAM_ID,
+                systemProgram: anchor.web3.SystemProgram.programId,
+              })
+              .transaction()
+          ).add(
+            createEnableCpiGuardInstruction(
+              tokenAccount,
+              admin.publicKey,
+              [],
+              TOKEN_2022_PROGRAM_ID
+            )
+          ),
+          signers: [admin, payer],
+        });
+      }
+
+      for (const tokenAccount of [guarded, unguarded]) {
+        await mintTo(
+          provider.connection,
+          payer,
+          mint.publicKey,
+          tokenAccount,
+          admin,
+          100,
+          [],
+          { commitment: "finalized", skipPreflight: true },
+          TOKEN_2022_PROGRAM_ID
+        );
+      }
+
+      // Rule must reject the guarded account and accept the unguarded one.
+      const checkRule = async (
+        rule: string,
+        build: (
+          tokenAccount: anchor.web3.PublicKey
+        ) => Promise<anchor.web3.Transaction>,
+        guardedAccount = guarded,
+        unguardedAccount = unguarded
+      ) => {
+        log(`Failed: ${rule} on guarded account`);
+        await rejectedWith(
+          sendAndConfirmTransaction({
+            connection: provider.connection,
+            transaction: await build(guardedAccount),
+            signers: [admin],
+          }),
+          Error
+        );
+
+        await fulfilled(
+          sendAndConfirmTransaction({
+            connection: provider.connection,
+            transaction: await build(unguardedAccount),
+            signers: [admin],
+          })
+        );
+        log(`Passed: ${rule} on unguarded account`);
+      };
+
+      await checkRule("approve", (tokenAccount) =>
+        opaqueProgram.methods
+          .approveDelegate(new anchor.BN(10))
+          .accounts({
+            tokenAccount,
+            delegate: receiver.publicKey,
+            owner: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .transaction()
+      );
+
+      await checkRule("owner transfer", (tokenAccount) =>
+        opaqueProgram.methods
+          .transferTokens(new anchor.BN(10))
+          .accounts({
+            mint: mint.publicKey,
+            tokenAccount,
+            toAccount: receiverAcc,
+            authority: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .transaction()
+      );
+
+      await checkRule("owner burn", (tokenAccount) =>
+        opaqueProgram.methods
+          .burnTokens(new anchor.BN(10))
+          .accounts({
+            mint: mint.publicKey,
+            tokenAccount,
+            authority: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .transaction()
+      );
+
+      await checkRule("set close authority", (tokenAccount) =>
+        opaqueProgram.methods
+          .setCloseAuthority(receiver.publicKey)
+          .accounts({
+            tokenAccount,
+            owner: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .transaction()
+      );
+
+      await checkRule(
+        "close to another wallet",
+        (tokenAccount) =>
+          opaqueProgram.methods
+            .closeTokenAccount()
+            .accounts({
+              tokenAccount,
+              destination: receiver.publicKey,
+              owner: admin.publicKey,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+            })
+            .transaction(),
+        emptyGuarded,
+        emptyUnguarded
+      );
+
+      await checkRule("set owner", (tokenAccount) =>
+        opaqueProgram.methods
+          .setOwner(receiver.publicKey)
+          .accounts({
+            tokenAccount,
+            owner: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .transaction()
+      );
+
+      log("Passed: close guarded account back to its owner");
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await opaqueProgram.methods
+          .closeTokenAccount()
+          .accounts({
+            tokenAccount: emptyGuarded,
+            destination: admin.publicKey,
+            owner: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .transaction(),
+        signers: [admin],
+      });
+
+      // A delegate approved outside of a CPI can still move and burn tokens.
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: new anchor.web3.Transaction().add(
+          createApproveInstruction(
+            guarded,
+            receiver.publicKey,
+            admin.publicKey,
+            20,
+            [],
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        signers: [admin],
+      });
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await opaqueProgram.methods
+          .transferTokens(new anchor.BN(10))
+          .accounts({
+            mint: mint.publicKey,
+            tokenAccount: guarded,
+            toAccount: receiverAcc,
+            authority: receiver.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .transaction(),
+        signers: [receiver],
+      });
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await opaqueProgram.methods
+          .burnTokens(new anchor.BN(10))
+          .accounts({
+            mint: mint.publicKey,
+            tokenAccount: guarded,
+            authority: receiver.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .transaction(),
+        signers: [receiver],
+      });
+
+      const account = await getAccount(
+        provider.connection,
+        guarded,
+        "finalized",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(account.amount, BigInt(80));
+    })
+  );
+});
@@ -5,6 +5,7 @@ import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   ExtensionType,
   TOKEN_2022_PROGRAM_ID,
+  getAccount,
   getAssociatedTokenAddress,
   getMintLen,
   getOrCreateAssociatedTokenAccount,
@@ -15,6 +16,9 @@ import {
   fetchAdminKeypair,
   fetchPayerKeypair,
   fetchReceiverKeypair,
+  initializeProgramConfig,
+  MEMO_PROGRAM_ID,
+  rejectedWith,
   sendAndConfirmTransaction,
 } from "./utils";
 import Debug from "debug";
@@ -40,6 +44,8 @@ describe("✅ token-extension: permanent delegate usage", () => {
 
     await airdrop(provider, receiver.publicKey, payer.publicKey);
 
+    const programConfig = await initializeProgramConfig(program);
+
     const mintLen = new anchor.BN(
       getMintLen([ExtensionType.PermanentDelegate])
     );
@@ -49,6 +55,7 @@ describe("✅ token-extension: permanent delegate usage", () => {
       transaction: await program.methods
         .permanentDelegate(mintLen)
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           payer: payer.publicKey,
           allMintRole: admin.publicKey,
@@ -74,6 +81,7 @@ describe("✅ token-extension: permanent delegate usage", () => {
       transaction: await program.methods
         .createAta()
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           payer: payer.publicKey,
           associatedToken: associatedTA,
@@ -101,6 +109,7 @@ describe("✅ token-extension: permanent delegate usage", () => {
       transaction: await program.methods
         .createAta()
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           payer: payer.publicKey,
           associatedToken: receiverATA,
@@ -121,6 +130,7 @@ describe("✅ token-extension: permanent delegate usage", () => {
       transaction: await program.methods
         .mintTo(new anchor.BN(100))
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           associatedToken: associatedTA,
@@ -137,6 +147,7 @@ describe("✅ token-extension: permanent delegate usage", () => {
       transaction: await program.methods
         .transferTo(new anchor.BN(50), new anchor.BN(0))
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           fromAcc: associatedTA,
@@ -154,6 +165,7 @@ describe("✅ token-extension: permanent delegate usage", () => {
       transaction: await program.methods
         .burnCpi(new anchor.BN(10))
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           from: receiverATA,
           token2022Program: TOKEN_2022_PROGRAM_ID,
@@ -175,5 +187,52 @@ describe("✅ token-extension: permanent delegate usage", () => {
       TOKEN_2022_PROGRAM_ID
     );
     expect(receiverAtaAfter.amount).to.equal(BigInt(40));
+
+    const clawbackTxId = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: await program.methods
+        .clawback(new anchor.BN(15), "Court order #42")
+        .accounts({
+          programConfig,
+          mint: mint.publicKey,
+          from: receiverATA,
+          treasury: associatedTA,
+          delegate: admin.publicKey,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          memoProgram: MEMO_PROGRAM_ID,
+        })
+        .transaction(),
+      signers: [admin],
+    });
+    log("Clawback to treasury by delegate authority 👇\ntxId", clawbackTxId);
+
+    const receiverAtaAfterClawback = await getAccount(
+      provider.connection,
+      receiverATA,
+      "finalized",
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(receiverAtaAfterClawback.amount).to.equal(BigInt(25));
+
+    log("Failed: clawback signed by a non delegate");
+    await rejectedWith(
+      sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .clawback(new anchor.BN(5), "Not the delegate")
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            from: receiverATA,
+            treasury: associatedTA,
+            delegate: payer.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            memoProgram: MEMO_PROGRAM_ID,
+          })
+          .transaction(),
+        signers: [payer],
+      }),
+      Error
+    );
   });
 });
@@ -0,0 +1,185 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenExtension } from "../target/types/token_extension";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  createAssociatedTokenAccountIdempotent,
+  getMintLen,
+  mintTo,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  fetchAdminKeypair,
+  fetchPayerKeypair,
+  fetchReceiverKeypair,
+  initializeProgramConfig,
+  programError,
+  rejectedWith,
+  runTest,
+  sendAndConfirmTransaction,
+} from "./utils";
+import Debug from "debug";
+
+const log = Debug("log: preconditions");
+
+describe("✅ tokenExtension: preconditions", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
+
+  it(
+    "transfer with a wrong fee fails with FeeMismatch",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      const receiver = fetchReceiverKeypair();
+
+      await airdrop(provider, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+      log("Mint", mint.publicKey.toBase58());
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .transferFeeConfig(
+            new anchor.BN(getMintLen([ExtensionType.TransferFeeConfig])),
+            admin.publicKey,
+            admin.publicKey,
+            100,
+            new anchor.BN(10_000)
+          )
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            payer: payer.publicKey,
+            allMintRole: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .transaction(),
+        signers: [admin, mint, payer],
+      });
+
+      const [adminATA, receiverATA] = await Promise.all(
+        [admin, receiver].map((wallet) =>
+          createAssociatedTokenAccountIdempotent(
+            provider.connection,
+            payer,
+            mint.publicKey,
+            wallet.publicKey,
+            { commitment: "finalized", skipPreflight: true },
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      );
+
+      await mintTo(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        adminATA,
+        admin,
+        10_000,
+        [],
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      log("Failed: 1% fee on 1_000 is 10, not 1");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .transferTo(new anchor.BN(1_000), new anchor.BN(1))
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+              fromAcc: adminATA,
+              toAcc: receiverATA,
+              authority: admin.publicKey,
+            })
+            .transaction(),
+          signers: [admin],
+        }),
+        Error,
+        programError(program, "FeeMismatch")
+      );
+    })
+  );
+
+  it(
+    "mint into a frozen account fails with AccountFrozen",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      await airdrop(provider, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+      log("Mint", mint.publicKey.toBase58());
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .defaultAccountState(
+            new anchor.BN(getMintLen([ExtensionType.DefaultAccountState])),
+            { frozen: {} }
+          )
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            payer: payer.publicKey,
+            allMintRole: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .transaction(),
+        signers: [admin, mint, payer],
+      });
+
+      // Starts frozen because of the mint's default account state.
+      const adminATA = await createAssociatedTokenAccountIdempotent(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        admin.publicKey,
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      log("Failed: mint_to a frozen account");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .mintTo(new anchor.BN(100))
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+              associatedToken: adminATA,
+              authority: admin.publicKey,
+            })
+            .transaction(),
+          signers: [admin],
+        }),
+        Error,
+        programError(program, "AccountFrozen")
+      );
+    })
+  );
+});
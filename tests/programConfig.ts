@@ -0,0 +1,421 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenExtension } from "../target/types/token_extension";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotent,
+  createMint,
+  getAccount,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  assert,
+  fetchAdminKeypair,
+  fetchPayerKeypair,
+  fetchReceiverKeypair,
+  initializeProgramConfig,
+  programError,
+  rejectedWith,
+  runTest,
+  sendAndConfirmTransaction,
+} from "./utils";
+import Debug from "debug";
+
+const log = Debug("log: programConfig");
+
+describe("✅ tokenExtension: program config", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
+
+  it(
+    "pause blocks mint_to until the admin resumes the program",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      await airdrop(provider, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+
+      await createMint(
+        provider.connection,
+        payer,
+        admin.publicKey,
+        null,
+        2,
+        mint,
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+      log("Mint", mint.publicKey.toBase58());
+
+      const adminATA = await createAssociatedTokenAccountIdempotent(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        admin.publicKey,
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const setPaused = async (paused: boolean) =>
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .setPaused(paused)
+            .accounts({ programConfig, admin: admin.publicKey })
+            .transaction(),
+          signers: [admin],
+        });
+
+      const mintTo = async () =>
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .mintTo(new anchor.BN(100))
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+              associatedToken: adminATA,
+              authority: admin.publicKey,
+            })
+            .transaction(),
+          signers: [admin],
+        });
+
+      const pauseTxId = await setPaused(true);
+      log("Paused txId ", pauseTxId);
+
+      try {
+        log("Failed: mint_to while paused");
+        await rejectedWith(mintTo(), Error);
+      } finally {
+        await setPaused(false);
+      }
+
+      const mintTxId = await mintTo();
+      log("Mint to after resume txId ", mintTxId);
+
+      const account = await getAccount(
+        provider.connection,
+        adminATA,
+        "finalized",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(account.amount, BigInt(100));
+    })
+  );
+
+  it(
+    "pause blocks authority, fee, rate and close instructions",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      await airdrop(provider, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+
+      await createMint(
+        provider.connection,
+        payer,
+        admin.publicKey,
+        null,
+        2,
+        mint,
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+      log("Mint", mint.publicKey.toBase58());
+
+      const adminATA = await createAssociatedTokenAccountIdempotent(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        admin.publicKey,
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const setPaused = async (paused: boolean) =>
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .setPaused(paused)
+            .accounts({ programConfig, admin: admin.publicKey })
+            .transaction(),
+          signers: [admin],
+        });
+
+      const paused = [
+        {
+          name: "set_authority",
+          signers: [payer, admin],
+          method: program.methods
+            .setAuthority({ mintTokens: {} }, payer.publicKey)
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              currentAuthority: admin.publicKey,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+            }),
+        },
+        {
+          name: "harvest_withheld_token",
+          signers: [payer],
+          method: program.methods
+            .harvestWithheldToken()
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts([
+              { pubkey: adminATA, isSigner: false, isWritable: true },
+            ]),
+        },
+        {
+          name: "update_rate",
+          signers: [payer, admin],
+          method: program.methods.updateRate(100).accounts({
+            programConfig,
+            mint: mint.publicKey,
+            rateAuthority: admin.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          }),
+        },
+        {
+          name: "close_token_accounts",
+          signers: [payer, admin],
+          method: program.methods
+            .closeTokenAccounts()
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              owner: admin.publicKey,
+              destination: admin.publicKey,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts([
+              { pubkey: adminATA, isSigner: false, isWritable: true },
+            ]),
+        },
+      ];
+
+      await setPaused(true);
+
+      try {
+        for (const { name, signers, method } of paused) {
+          log(`Failed: ${name} while paused`);
+          await rejectedWith(
+            sendAndConfirmTransaction({
+              connection: provider.connection,
+              transaction: await method.transaction(),
+              signers,
+            }),
+            Error,
+            programError(program, "ProgramPaused")
+          );
+        }
+      } finally {
+        await setPaused(false);
+      }
+
+      const account = await getAccount(
+        provider.connection,
+        adminATA,
+        "finalized",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(account.amount, BigInt(0));
+    })
+  );
+
+  it(
+    "freeze stays available while paused, thaw does not",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      const receiver = fetchReceiverKeypair();
+
+      await airdrop(provider, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+
+      await createMint(
+        provider.connection,
+        payer,
+        admin.publicKey,
+        admin.publicKey,
+        2,
+        mint,
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+      log("Mint", mint.publicKey.toBase58());
+
+      const receiverATA = await createAssociatedTokenAccountIdempotent(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        receiver.publicKey,
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const [freezeRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("freeze-record"), receiverATA.toBuffer()],
+        program.programId
+      );
+
+      const batch = (
+        method: ReturnType<typeof program.methods.freezeAccounts>
+      ) =>
+        method
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            freezeAuthority: admin.publicKey,
+            payer: payer.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .remainingAccounts([
+            { pubkey: receiverATA, isSigner: false, isWritable: true },
+            { pubkey: freezeRecord, isSigner: false, isWritable: true },
+          ])
+          .transaction();
+
+      const setPaused = async (paused: boolean) =>
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .setPaused(paused)
+            .accounts({ programConfig, admin: admin.publicKey })
+            .transaction(),
+          signers: [admin],
+        });
+
+      await setPaused(true);
+
+      try {
+        const freezeTxId = await sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await batch(program.methods.freezeAccounts(1)),
+          signers: [admin, payer],
+        });
+        log("Freeze accounts while paused txId ", freezeTxId);
+
+        log("Failed: thaw_accounts while paused");
+        await rejectedWith(
+          sendAndConfirmTransaction({
+            connection: provider.connection,
+            transaction: await batch(program.methods.thawAccounts(1)),
+            signers: [admin, payer],
+          }),
+          Error,
+          programError(program, "ProgramPaused")
+        );
+      } finally {
+        await setPaused(false);
+      }
+
+      const account = await getAccount(
+        provider.connection,
+        receiverATA,
+        "finalized",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.isTrue(account.isFrozen);
+    })
+  );
+
+  it(
+    "hand the admin role over in two steps and back",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const receiver = fetchReceiverKeypair();
+
+      await airdrop(provider, receiver.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const handover = async (
+        from: anchor.web3.Keypair,
+        to: anchor.web3.Keypair
+      ) => {
+        await sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .proposeAdmin(to.publicKey)
+            .accounts({ programConfig, admin: from.publicKey })
+            .transaction(),
+          signers: [from],
+        });
+
+        return sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .acceptAdmin()
+            .accounts({ programConfig, pendingAdmin: to.publicKey })
+            .transaction(),
+          signers: [to],
+        });
+      };
+
+      log("Failed: accept without a proposal");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .acceptAdmin()
+            .accounts({ programConfig, pendingAdmin: receiver.publicKey })
+            .transaction(),
+          signers: [receiver],
+        }),
+        Error
+      );
+
+      const acceptTxId = await handover(admin, receiver);
+      log("Admin handed over txId ", acceptTxId);
+
+      let config = await program.account.programConfig.fetch(programConfig);
+      assert.isTrue(config.admin.equals(receiver.publicKey));
+      assert.isNull(config.pendingAdmin);
+
+      log("Failed: former admin pauses the program");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .setPaused(true)
+            .accounts({ programConfig, admin: admin.publicKey })
+            .transaction(),
+          signers: [admin],
+        }),
+        Error
+      );
+
+      // Other suites expect the upgrade authority to stay admin.
+      await handover(receiver, admin);
+
+      config = await program.account.programConfig.fetch(programConfig);
+      assert.isTrue(config.admin.equals(admin.publicKey));
+    })
+  );
+});
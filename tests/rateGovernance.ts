@@ -0,0 +1,255 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenExtension } from "../target/types/token_extension";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  getMintLen,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  assert,
+  fetchAdminKeypair,
+  fetchPayerKeypair,
+  fetchReceiverKeypair,
+  getTokenExtensionState,
+  initializeProgramConfig,
+  programError,
+  rejectedWith,
+  runTest,
+  sendAndConfirmTransaction,
+} from "./utils";
+import Debug from "debug";
+
+const log = Debug("log: rateGovernance");
+
+describe("✅ tokenExtension: interest rate governance", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
+
+  it(
+    "propose and execute governed rate change",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      await airdrop(provider, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+      log("Mint", mint.publicKey.toBase58());
+
+      const [rateGovernance] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("rate-governance"), mint.publicKey.toBuffer()],
+        program.programId
+      );
+      log("Rate governance", rateGovernance.toBase58());
+
+      const mintTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .interestBearingToken(
+            new anchor.BN(getMintLen([ExtensionType.InterestBearingConfig])),
+            50
+          )
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            payer: payer.publicKey,
+            allMintRole: admin.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .transaction(),
+        signers: [admin, mint, payer],
+      });
+      log("Interest bearing mint txId ", mintTxId);
+
+      const initializeGovernance = async (minRate: number, maxRate: number) =>
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .initializeRateGovernance(
+              minRate,
+              maxRate,
+              100,
+              new anchor.BN(86400),
+              new anchor.BN(0)
+            )
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              rateGovernance,
+              payer: payer.publicKey,
+              rateAuthority: admin.publicKey,
+              admin: admin.publicKey,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+              systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .transaction(),
+          signers: [admin, payer],
+        });
+
+      log("Failed: current rate outside the governance bounds");
+      await rejectedWith(
+        initializeGovernance(100, 500),
+        Error,
+        programError(program, "RateOutOfBounds")
+      );
+
+      const governanceTxId = await initializeGovernance(0, 500);
+      log("Rate governance initialized txId ", governanceTxId);
+
+      log("Failed: rate out of bounds");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .proposeRate(600)
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              rateGovernance,
+              admin: admin.publicKey,
+            })
+            .transaction(),
+          signers: [admin],
+        }),
+        Error
+      );
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .proposeRate(120)
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            rateGovernance,
+            admin: admin.publicKey,
+          })
+          .transaction(),
+        signers: [admin],
+      });
+
+      const executeTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .executeRate()
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            rateGovernance,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .transaction(),
+        signers: [payer],
+      });
+      log("Rate executed txId ", executeTxId);
+
+      const currentRate = await getTokenExtensionState(
+        provider.connection,
+        mint.publicKey,
+        "interestBearingConfig",
+        "currentRate"
+      );
+      assert.equal(120, currentRate);
+
+      log("Failed: change exceeds maximum per period, rejected when proposed");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .proposeRate(200)
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              rateGovernance,
+              admin: admin.publicKey,
+            })
+            .transaction(),
+          signers: [admin],
+        }),
+        Error,
+        programError(program, "RateChangeTooLarge")
+      );
+
+      log("Failed: proposal by someone other than the admin");
+      const intruder = fetchReceiverKeypair();
+      await airdrop(provider, intruder.publicKey);
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .proposeRate(130)
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              rateGovernance,
+              admin: intruder.publicKey,
+            })
+            .transaction(),
+          signers: [intruder],
+        }),
+        Error,
+        programError(program, "Unauthorized")
+      );
+
+      const proposalAccounts = {
+        programConfig,
+        mint: mint.publicKey,
+        rateGovernance,
+        admin: admin.publicKey,
+      };
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .proposeRate(130)
+          .accounts(proposalAccounts)
+          .transaction(),
+        signers: [admin],
+      });
+
+      const cancelTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .cancelRate()
+          .accounts(proposalAccounts)
+          .transaction(),
+        signers: [admin],
+      });
+      log("Rate proposal cancelled txId ", cancelTxId);
+
+      const governance = await program.account.rateGovernance.fetch(
+        rateGovernance
+      );
+      assert.isNull(governance.pendingRate);
+
+      log("Failed: execute after the proposal was cancelled");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .executeRate()
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              rateGovernance,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+            })
+            .transaction(),
+          signers: [payer],
+        }),
+        Error,
+        programError(program, "NoPendingRate")
+      );
+    })
+  );
+});
@@ -3,13 +3,21 @@ import { Program } from "@coral-xyz/anchor";
 import { TokenExtension } from "../target/types/token_extension";
 import {
   TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
   createAssociatedTokenAccountIdempotent,
+  createEnableCpiGuardInstruction,
   createMint,
+  getAccount,
+  getAccountLen,
+  getCpiGuard,
+  getMemoTransfer,
 } from "@solana/spl-token";
 import {
   airdrop,
+  assert,
   fetchAdminKeypair,
   fetchPayerKeypair,
+  initializeProgramConfig,
   sendAndConfirmTransaction,
 } from "./utils";
 import Debug from "debug";
@@ -30,6 +38,8 @@ describe("✅ token-extension: realloc usage", () => {
 
     await airdrop(provider, payer.publicKey);
 
+    const programConfig = await initializeProgramConfig(program);
+
     const mint = anchor.web3.Keypair.generate();
 
     const decimals = 2;
@@ -62,6 +72,7 @@ describe("✅ token-extension: realloc usage", () => {
       transaction: await program.methods
         .realloc()
         .accounts({
+          programConfig,
           tokenAccount: associatedTokenAcc,
           payer: payer.publicKey,
           allMintRole: admin.publicKey,
@@ -74,4 +85,98 @@ describe("✅ token-extension: realloc usage", () => {
 
     log("Realloc txId ", rTid);
   });
+
+  it("realloc token account for memo transfer and cpi guard at once", async () => {
+    const admin = fetchAdminKeypair();
+
+    const payer = fetchPayerKeypair();
+
+    await airdrop(provider, payer.publicKey);
+
+    const programConfig = await initializeProgramConfig(program);
+
+    const mint = anchor.web3.Keypair.generate();
+
+    await createMint(
+      provider.connection,
+      admin,
+      admin.publicKey,
+      admin.publicKey,
+      2,
+      mint,
+      { commitment: "finalized", skipPreflight: true },
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const associatedTokenAcc = await createAssociatedTokenAccountIdempotent(
+      provider.connection,
+      admin,
+      mint.publicKey,
+      admin.publicKey,
+      { commitment: "finalized", skipPreflight: true },
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const accountLamports = await provider.connection.getBalance(
+      associatedTokenAcc,
+      "finalized"
+    );
+    const rentDifference =
+      (await provider.connection.getMinimumBalanceForRentExemption(
+        getAccountLen([
+          ExtensionType.ImmutableOwner,
+          ExtensionType.MemoTransfer,
+          ExtensionType.CpiGuard,
+        ])
+      )) - accountLamports;
+
+    const payerBalBefore = await provider.connection.getBalance(
+      payer.publicKey,
+      "finalized"
+    );
+
+    const rTid = await sendAndConfirmTransaction({
+      connection: provider.connection,
+      transaction: (
+        await program.methods
+          .reallocAccount([{ memoTransfer: {} }, { cpiGuard: {} }])
+          .accounts({
+            programConfig,
+            tokenAccount: associatedTokenAcc,
+            payer: payer.publicKey,
+            owner: admin.publicKey,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .transaction()
+      ).add(
+        createEnableCpiGuardInstruction(
+          associatedTokenAcc,
+          admin.publicKey,
+          [],
+          TOKEN_2022_PROGRAM_ID
+        )
+      ),
+      // admin pays the fee, so payer is only charged the rent difference
+      signers: [admin, payer],
+    });
+
+    log("Realloc account txId ", rTid);
+
+    const payerBalAfter = await provider.connection.getBalance(
+      payer.publicKey,
+      "finalized"
+    );
+    assert.equal(payerBalBefore - payerBalAfter, rentDifference);
+
+    const account = await getAccount(
+      provider.connection,
+      associatedTokenAcc,
+      "finalized",
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert.isTrue(getMemoTransfer(account).requireIncomingTransferMemos);
+    assert.isTrue(getCpiGuard(account).lockCpi);
+  });
 });
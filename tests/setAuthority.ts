@@ -0,0 +1,196 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenExtension } from "../target/types/token_extension";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  createMint,
+  createMultisig,
+  getMint,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  assert,
+  fetchAdminKeypair,
+  fetchPayerKeypair,
+  fetchReceiverKeypair,
+  initializeProgramConfig,
+  programError,
+  rejectedWith,
+  runTest,
+  sendAndConfirmTransaction,
+} from "./utils";
+import Debug from "debug";
+
+const log = Debug("log: setAuthority");
+
+describe("✅ tokenExtension: set authority", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
+
+  it(
+    "rotate mint and freeze authority with a signer and a multisig",
+    runTest(async () => {
+      const admin = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      const receiver = fetchReceiverKeypair();
+
+      await airdrop(provider, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+
+      await createMint(
+        provider.connection,
+        payer,
+        admin.publicKey,
+        admin.publicKey,
+        2,
+        mint,
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+      log("Mint", mint.publicKey.toBase58());
+
+      const multisig = await createMultisig(
+        provider.connection,
+        payer,
+        [admin.publicKey, payer.publicKey],
+        2,
+        anchor.web3.Keypair.generate(),
+        { commitment: "finalized", skipPreflight: true },
+        TOKEN_2022_PROGRAM_ID
+      );
+      log("Multisig", multisig.toBase58());
+
+      const setAuthorityIx = (
+        kind: object,
+        newAuthority: anchor.web3.PublicKey | null,
+        currentAuthority: anchor.web3.PublicKey,
+        signers: anchor.web3.PublicKey[] = []
+      ) =>
+        program.methods
+          .setAuthority(kind as any, newAuthority)
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            currentAuthority,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .remainingAccounts(
+            signers.map((pubkey) => ({
+              pubkey,
+              isSigner: true,
+              isWritable: false,
+            }))
+          )
+          .instruction();
+
+      // currentAuthority is a signer or a multisig, so mark the signer explicitly
+      const signedBy = (
+        ix: anchor.web3.TransactionInstruction,
+        signer: anchor.web3.PublicKey
+      ) => {
+        ix.keys.find((key) => key.pubkey.equals(signer)).isSigner = true;
+        return new anchor.web3.Transaction().add(ix);
+      };
+
+      const mintTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: signedBy(
+          await setAuthorityIx(
+            { mintTokens: {} },
+            receiver.publicKey,
+            admin.publicKey
+          ),
+          admin.publicKey
+        ),
+        signers: [payer, admin],
+      });
+      log("Mint authority rotated txId ", mintTxId);
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: signedBy(
+          await setAuthorityIx({ freezeAccount: {} }, multisig, admin.publicKey),
+          admin.publicKey
+        ),
+        signers: [payer, admin],
+      });
+
+      log("Failed: interest rate authority of a mint without the extension");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: signedBy(
+            await setAuthorityIx(
+              { interestRate: {} },
+              receiver.publicKey,
+              receiver.publicKey
+            ),
+            receiver.publicKey
+          ),
+          signers: [payer, receiver],
+        }),
+        Error,
+        programError(program, "ExtensionMissing")
+      );
+
+      log("Failed: multisig below threshold");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: new anchor.web3.Transaction().add(
+            await setAuthorityIx({ freezeAccount: {} }, null, multisig, [
+              payer.publicKey,
+            ])
+          ),
+          signers: [payer],
+        }),
+        Error
+      );
+
+      log("Failed: the same multisig signer passed twice");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: new anchor.web3.Transaction().add(
+            await setAuthorityIx({ freezeAccount: {} }, null, multisig, [
+              payer.publicKey,
+              payer.publicKey,
+            ])
+          ),
+          signers: [payer],
+        }),
+        Error,
+        programError(program, "MissingAuthoritySignature")
+      );
+
+      const freezeTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: new anchor.web3.Transaction().add(
+          await setAuthorityIx({ freezeAccount: {} }, null, multisig, [
+            admin.publicKey,
+            payer.publicKey,
+          ])
+        ),
+        signers: [payer, admin],
+      });
+      log("Freeze authority removed by multisig txId ", freezeTxId);
+
+      const mintInfo = await getMint(
+        provider.connection,
+        mint.publicKey,
+        "finalized",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.isTrue(mintInfo.mintAuthority.equals(receiver.publicKey));
+      assert.isNull(mintInfo.freezeAuthority);
+    })
+  );
+});
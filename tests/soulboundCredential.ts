@@ -0,0 +1,192 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenExtension } from "../target/types/token_extension";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  getTokenMetadata,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  assert,
+  fetchAdminKeypair,
+  fetchPayerKeypair,
+  fetchReceiverKeypair,
+  initializeProgramConfig,
+  programError,
+  rejectedWith,
+  runTest,
+  sendAndConfirmTransaction,
+} from "./utils";
+import Debug from "debug";
+
+const log = Debug("log: soulboundCredential");
+
+describe("✅ tokenExtension: soulbound credential", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TokenExtension as Program<TokenExtension>;
+
+  it(
+    "issue, verify and revoke a credential",
+    runTest(async () => {
+      const issuer = fetchAdminKeypair();
+
+      const payer = fetchPayerKeypair();
+
+      const holder = fetchReceiverKeypair();
+
+      await airdrop(provider, payer.publicKey);
+
+      const programConfig = await initializeProgramConfig(program);
+
+      const mint = anchor.web3.Keypair.generate();
+      log("Mint", mint.publicKey.toBase58());
+
+      await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .createCredentialMint(
+            "KYC Level 2",
+            "KYC2",
+            "https://example.com/kyc2.json"
+          )
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            payer: payer.publicKey,
+            issuer: issuer.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .transaction(),
+        signers: [payer, mint, issuer],
+      });
+
+      const holderAccount = getAssociatedTokenAddressSync(
+        mint.publicKey,
+        holder.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const expiresAt = Math.floor(Date.now() / 1000) + 3600;
+
+      const issueTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .issueCredential(new anchor.BN(expiresAt), [
+            { key: "country", value: "DE" },
+          ])
+          .accounts({
+            programConfig,
+            mint: mint.publicKey,
+            holder: holder.publicKey,
+            holderAccount,
+            issuer: issuer.publicKey,
+            payer: payer.publicKey,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .transaction(),
+        signers: [payer, issuer],
+      });
+      log("Issue credential txId ", issueTxId);
+
+      const metadata = await getTokenMetadata(
+        provider.connection,
+        mint.publicKey,
+        "finalized",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.deepInclude(metadata.additionalMetadata, [
+        "expires_at",
+        expiresAt.toString(),
+      ]);
+      assert.deepInclude(metadata.additionalMetadata, ["country", "DE"]);
+
+      const verifyAccounts = { mint: mint.publicKey, holderAccount };
+
+      let status = await program.methods
+        .verifyCredential()
+        .accounts(verifyAccounts)
+        .view();
+      assert.isTrue(status.valid);
+      assert.isTrue(status.holder.equals(holder.publicKey));
+
+      const revokeAccounts = {
+        programConfig,
+        mint: mint.publicKey,
+        holderAccount,
+        issuer: issuer.publicKey,
+        payer: payer.publicKey,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      };
+
+      log("Failed: revoke by someone other than the issuer");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .revokeCredential()
+            .accounts({ ...revokeAccounts, issuer: payer.publicKey })
+            .transaction(),
+          signers: [payer],
+        }),
+        Error
+      );
+
+      const revokeTxId = await sendAndConfirmTransaction({
+        connection: provider.connection,
+        transaction: await program.methods
+          .revokeCredential()
+          .accounts(revokeAccounts)
+          .transaction(),
+        signers: [payer, issuer],
+      });
+      log("Revoke credential txId ", revokeTxId);
+
+      status = await program.methods
+        .verifyCredential()
+        .accounts(verifyAccounts)
+        .view();
+      assert.isFalse(status.held);
+      assert.isNotNull(status.revokedAt);
+      assert.isFalse(status.valid);
+
+      log("Failed: issue again from the revoked mint");
+      await rejectedWith(
+        sendAndConfirmTransaction({
+          connection: provider.connection,
+          transaction: await program.methods
+            .issueCredential(new anchor.BN(expiresAt), [])
+            .accounts({
+              programConfig,
+              mint: mint.publicKey,
+              holder: payer.publicKey,
+              holderAccount: getAssociatedTokenAddressSync(
+                mint.publicKey,
+                payer.publicKey,
+                false,
+                TOKEN_2022_PROGRAM_ID
+              ),
+              issuer: issuer.publicKey,
+              payer: payer.publicKey,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .transaction(),
+          signers: [payer, issuer],
+        }),
+        Error,
+        programError(program, "CredentialRevoked")
+      );
+    })
+  );
+});
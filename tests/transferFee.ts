@@ -17,6 +17,7 @@ import {
   fetchReceiverKeypair,
   findWithheldTokenAndRemainingAccount,
   getWithheldAmount,
+  initializeProgramConfig,
   runTest,
   sendAndConfirmTransaction,
 } from "./utils";
@@ -46,6 +47,8 @@ describe("✅ token-extension transfer fee", () => {
 
       await airdrop(provider, payer.publicKey);
 
+      const programConfig = await initializeProgramConfig(program);
+
       const mint = anchor.web3.Keypair.generate();
       log("Mint", mint.publicKey.toBase58());
 
@@ -62,6 +65,7 @@ describe("✅ token-extension transfer fee", () => {
             new anchor.BN(100).mul(new anchor.BN(10).pow(new anchor.BN(2)))
           )
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             payer: payer.publicKey,
             allMintRole: admin.publicKey,
@@ -91,6 +95,7 @@ describe("✅ token-extension transfer fee", () => {
             new anchor.BN(2000).mul(new anchor.BN(10).pow(new anchor.BN(2)))
           )
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             token2022Program: TOKEN_2022_PROGRAM_ID,
             associatedToken: associatedTokenAcc,
@@ -147,6 +152,7 @@ describe("✅ token-extension transfer fee", () => {
         transaction: await program.methods
           .transferTo(transferAmount, fee)
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             token2022Program: TOKEN_2022_PROGRAM_ID,
             fromAcc: associatedTokenAcc,
@@ -173,6 +179,7 @@ describe("✅ token-extension transfer fee", () => {
         transaction: await program.methods
           .withdrawWithheldAccount()
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             destination: associatedTokenAcc,
             token2022Program: TOKEN_2022_PROGRAM_ID,
@@ -204,6 +211,7 @@ describe("✅ token-extension transfer fee", () => {
         await program.methods
           .transferTo(transferAmount, fee)
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             token2022Program: TOKEN_2022_PROGRAM_ID,
             fromAcc: associatedTokenAcc,
@@ -216,6 +224,7 @@ describe("✅ token-extension transfer fee", () => {
         await program.methods
           .transferTo(transferAmount, fee)
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             token2022Program: TOKEN_2022_PROGRAM_ID,
             fromAcc: associatedTokenAcc,
@@ -236,6 +245,7 @@ describe("✅ token-extension transfer fee", () => {
         transaction: await program.methods
           .harvestWithheldToken()
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             token2022Program: TOKEN_2022_PROGRAM_ID,
           })
@@ -264,6 +274,7 @@ describe("✅ token-extension transfer fee", () => {
         transaction: await program.methods
           .withdrawWithheldMint()
           .accounts({
+            programConfig,
             mint: mint.publicKey,
             token2022Program: TOKEN_2022_PROGRAM_ID,
             authority: admin.publicKey,
@@ -14,6 +14,7 @@ import {
   fetchAdminKeypair,
   fetchPayerKeypair,
   fetchReceiverKeypair,
+  initializeProgramConfig,
   sendAndConfirmTransaction,
 } from "./utils";
 import Debug from "debug";
@@ -38,6 +39,8 @@ describe("✅ token-extension: transfer hook", () => {
 
     await airdrop(provider, receiver.publicKey, payer.publicKey);
 
+    const programConfig = await initializeProgramConfig(program);
+
     const mint = anchor.web3.Keypair.generate();
     log("Mint", mint.publicKey.toBase58());
 
@@ -59,6 +62,7 @@ describe("✅ token-extension: transfer hook", () => {
       transaction: await program.methods
         .initializeHookMint(mintLen, transferHookProgram.programId)
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           payer: payer.publicKey,
           allMintRole: admin.publicKey,
@@ -126,6 +130,7 @@ describe("✅ token-extension: transfer hook", () => {
       transaction: await program.methods
         .transferHookToken(new anchor.BN(2 * 10 ** decimals), decimals)
         .accounts({
+          programConfig,
           mint: mint.publicKey,
           fromAcc: associatedTokenAcc,
           toAcc: receiverATA,
@@ -107,6 +107,16 @@ export async function rejectedWith<T>(
   await expect(promise).to.be.rejectedWith(constructor, expected, message);
 }
 
+// Transactions are sent with skipPreflight, so a failure only carries the
+// custom error code, not the error name.
+export function programError(
+  program: anchor.Program<any>,
+  name: string
+): RegExp {
+  const error = program.idl.errors.find((error) => error.name === name);
+  return new RegExp(`"Custom":${error.code}\\b`);
+}
+
 export async function findWithheldTokenAndRemainingAccount({
   connection,
   mint,
@@ -200,3 +210,68 @@ export async function airdrop(
     })
   );
 }
+
+export const PROGRAM_CONFIG_SEED = "program-config";
+
+export const findProgramConfigAddress = (
+  programId: anchor.web3.PublicKey
+): anchor.web3.PublicKey =>
+  anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from(PROGRAM_CONFIG_SEED)],
+    programId
+  )[0];
+
+// Gated instructions need the program config, so create it once with the
+// upgrade authority (the admin keypair) as the first admin.
+export async function initializeProgramConfig(
+  program: anchor.Program<any>
+): Promise<anchor.web3.PublicKey> {
+  const programConfig = findProgramConfigAddress(program.programId);
+  const connection = program.provider.connection;
+
+  if (await connection.getAccountInfo(programConfig, "finalized")) {
+    return programConfig;
+  }
+
+  const admin = fetchAdminKeypair();
+  const payer = fetchPayerKeypair();
+  await airdrop(program.provider as anchor.AnchorProvider, payer.publicKey);
+
+  const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
+
+  await sendAndConfirmTransaction({
+    connection,
+    transaction: await program.methods
+      .initializeProgramConfig()
+      .accounts({
+        programConfig,
+        program: program.programId,
+        programData,
+        admin: admin.publicKey,
+        payer: payer.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction(),
+    signers: [admin, payer],
+  });
+  log("Program config ", programConfig.toBase58());
+
+  return programConfig;
+}
+
+// Decode the Anchor events a confirmed transaction emitted through its logs.
+export async function fetchEvents(
+  program: anchor.Program<any>,
+  txId: string
+): Promise<anchor.Event[]> {
+  const tx = await program.provider.connection.getTransaction(txId, {
+    commitment: "finalized",
+    maxSupportedTransactionVersion: 0,
+  });
+  const parser = new anchor.EventParser(program.programId, program.coder);
+
+  return Array.from(parser.parseLogs(tx.meta.logMessages));
+}